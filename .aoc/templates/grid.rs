@@ -0,0 +1,30 @@
+// Day {{DAY}}: {{TITLE}}
+// https://adventofcode.com/{{YEAR}}/day/{{DAY}}
+
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let _grid = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let _grid = parse(input);
+    None
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", {{DAY}})
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::example_tests!(part_one, part_two);
+}
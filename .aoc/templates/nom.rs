@@ -0,0 +1,43 @@
+// Day {{DAY}}: {{TITLE}}
+// https://adventofcode.com/{{YEAR}}/day/{{DAY}}
+
+use advent_of_code::error::ParseError;
+use nom::{
+    character::complete::{line_ending, u32 as nom_u32},
+    multi::separated_list1,
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(line_ending, nom_u32)(input)
+}
+
+fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    parse_input(input)
+        .map(|(_, values)| values)
+        .map_err(|e| ParseError::from_nom(input, e))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let _values = parse(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let _values = parse(input)?;
+    Ok(None)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", {{DAY}})
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::example_tests!(part_one, part_two);
+}
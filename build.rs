@@ -0,0 +1,76 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Registers every solution in `src/bin/NN.rs` and `src/<year>/bin/NN.rs` with the library
+ * so `cargo all` can run them in-process.
+ */
+use std::{env, fs, path::Path};
+
+/// `(day, path)` of every solution in a directory.
+type Days = Vec<(u8, String)>;
+
+fn find_days(dir: &Path) -> Days {
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut days: Days = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            if path.extension()? != "rs" || stem.len() != 2 {
+                return None;
+            }
+            let day = stem.parse().ok()?;
+            Some((day, path.to_str()?.to_string()))
+        })
+        .collect();
+    days.sort();
+    days
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src_dir = Path::new(&manifest_dir).join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    // `None` stands for the default year, whose solutions live in `src/bin`.
+    let mut years: Vec<(Option<i16>, Days)> = vec![(None, find_days(&src_dir.join("bin")))];
+
+    let mut other_years: Vec<i16> = fs::read_dir(&src_dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+    other_years.sort();
+    for year in other_years {
+        let days = find_days(&src_dir.join(year.to_string()).join("bin"));
+        years.push((Some(year), days));
+    }
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (year, days) in years {
+        for (day, path) in days {
+            let (module, year) = match year {
+                Some(year) => (format!("y{}_day{:02}", year, day), year.to_string()),
+                None => (format!("day{:02}", day), "crate::DEFAULT_YEAR".to_string()),
+            };
+            modules.push_str(&format!(
+                "#[allow(dead_code)]\n#[path = {:?}]\nmod {};\n",
+                path, module
+            ));
+            entries.push_str(&format!(
+                "    Day {{\n        year: {year},\n        day: {day},\n        part_one: |input, mode| crate::run_part({year}, {day}, 1, {module}::part_one, input, mode),\n        part_two: |input, mode| crate::run_part({year}, {day}, 2, {module}::part_two, input, mode),\n    }},\n",
+            ));
+        }
+    }
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(
+        out_path,
+        format!("{modules}\npub const DAYS: &[Day] = &[\n{entries}];\n"),
+    )
+    .unwrap();
+}
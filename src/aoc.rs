@@ -0,0 +1,360 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::puzzle;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/Spirans/advent-of-code2022 by ureq";
+
+#[derive(Debug)]
+pub enum Error {
+    /// Neither `AOC_SESSION` nor `~/.adventofcode.session` holds a session cookie.
+    MissingSession,
+    Io(io::Error),
+    Request(Box<ureq::Error>),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or write it to ~/.adventofcode.session."
+            ),
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Request(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ureq::Error> for Error {
+    fn from(e: ureq::Error) -> Self {
+        Error::Request(Box::new(e))
+    }
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently.
+    Wait {
+        seconds: u32,
+    },
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Whether the answer itself was judged to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+
+    fn parse(html: &str) -> Self {
+        let text = puzzle::article_text(html);
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::Wait {
+                seconds: parse_wait_time(&text).unwrap_or(60),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::Wait { seconds } => write!(f, "wait {}s", seconds),
+            Outcome::WrongLevel => write!(f, "wrong level"),
+            Outcome::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+/// Parses the "You have 1m 5s left to wait" hint of a rate-limited submission.
+fn parse_wait_time(text: &str) -> Option<u32> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    text[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.len() - 1);
+            let value: u32 = value.parse().ok()?;
+            match unit {
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+/// Puzzles unlock at midnight US/Eastern. December is always outside of daylight saving time.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Days since the unix epoch of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The moment the puzzle of a day is released.
+pub fn unlock_time(year: i16, day: u8) -> SystemTime {
+    let days = days_from_civil(i64::from(year), 12, i64::from(day));
+    UNIX_EPOCH + Duration::from_secs(days as u64 * 86400 + UNLOCK_HOUR_UTC * 3600)
+}
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, Error> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .ok_or(Error::MissingSession)?;
+    let path = PathBuf::from(home).join(".adventofcode.session");
+
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(Error::MissingSession),
+    }
+}
+
+/// A client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Creates a client for the base url in `AOC_BASE_URL` (default: https://adventofcode.com)
+    /// using the session from [`read_session`].
+    pub fn from_env() -> Result<Self, Error> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(&base_url, &read_session()?))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// The puzzle page. Includes the description of part two once part one is solved.
+    pub fn puzzle(&self, year: i16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        let body = ureq::get(&url)
+            .set("Cookie", &self.cookie())
+            .set("User-Agent", USER_AGENT)
+            .call()?
+            .into_string()?;
+        Ok(body)
+    }
+
+    pub fn input(&self, year: i16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let body = ureq::get(&url)
+            .set("Cookie", &self.cookie())
+            .set("User-Agent", USER_AGENT)
+            .call()?
+            .into_string()?;
+        Ok(body)
+    }
+
+    pub fn submit(&self, year: i16, day: u8, part: u8, answer: &str) -> Result<Outcome, Error> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let body = ureq::post(&url)
+            .set("Cookie", &self.cookie())
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+        Ok(Outcome::parse(&body))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves a single request with `body` on a local port.
+    /// Returns the base url and a handle resolving to the raw request that was received.
+    pub(crate) fn serve_once(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            Outcome::parse("<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>"),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse("<article><p>That's not the right answer; your answer is too high. Please wait one minute.</p></article>"),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse(
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+            ),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse(
+                "<article><p>That's not the right answer. If you're stuck, ...</p></article>"
+            ),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            Outcome::parse("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>"),
+            Outcome::Wait { seconds: 65 }
+        );
+        assert_eq!(
+            Outcome::parse("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Outcome::WrongLevel
+        );
+        assert_eq!(
+            Outcome::parse("<p>Something <b>else</b></p>"),
+            Outcome::Unknown("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2022-12-01T05:00:00Z
+        assert_eq!(
+            unlock_time(2022, 1),
+            UNIX_EPOCH + Duration::from_secs(1669870800)
+        );
+        // 2015-12-25T05:00:00Z
+        assert_eq!(
+            unlock_time(2015, 25),
+            UNIX_EPOCH + Duration::from_secs(1451019600)
+        );
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, handle) = serve_once(200, "1000\n2000\n");
+
+        let client = Client::new(&base_url, "abc");
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn test_input_error_status() {
+        let (base_url, handle) = serve_once(400, "Puzzle inputs differ by user.");
+
+        let client = Client::new(&base_url, "expired");
+        assert!(matches!(client.input(2022, 1), Err(Error::Request(_))));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, handle) = serve_once(
+            200,
+            "<html><article><p>That's the right answer!</p></article></html>",
+        );
+
+        let client = Client::new(&base_url, "abc");
+        assert_eq!(client.submit(2022, 7, 2, "1234").unwrap(), Outcome::Correct);
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/7/answer HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Time spent running a solver before measurements start, so caches and lazy statics are warm.
+const WARM_UP_TIME: Duration = Duration::from_millis(100);
+/// Measurements stop after this much time has passed, once `MIN_ITERATIONS` are recorded.
+const MEASUREMENT_TIME: Duration = Duration::from_secs(1);
+const MIN_ITERATIONS: usize = 10;
+const MAX_ITERATIONS: usize = 10_000;
+
+/// Summary of repeated timings of a single solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Returns `None` for an empty sample.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Some(Stats {
+            iterations: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, iterations: {}",
+            self.median, self.mean, self.stddev, self.min, self.iterations
+        )
+    }
+}
+
+/// Runs `func` repeatedly and returns the answer of the first run along with timing statistics.
+/// Input and result are passed through `black_box` so the compiler cannot optimize the work away.
+pub fn bench<T>(func: impl Fn(&str) -> T, input: &str) -> (T, Stats) {
+    let result = black_box(func(black_box(input)));
+
+    let warm_up = Instant::now();
+    while warm_up.elapsed() < WARM_UP_TIME {
+        black_box(func(black_box(input)));
+    }
+
+    let mut samples = Vec::new();
+    let measurement = Instant::now();
+    while samples.len() < MAX_ITERATIONS
+        && (samples.len() < MIN_ITERATIONS || measurement.elapsed() < MEASUREMENT_TIME)
+    {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        samples.push(timer.elapsed());
+    }
+
+    (result, Stats::from_samples(&samples).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+
+        let stats = Stats::from_samples(&[
+            Duration::from_micros(4),
+            Duration::from_micros(2),
+            Duration::from_micros(8),
+            Duration::from_micros(6),
+        ])
+        .unwrap();
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.stddev.as_nanos(), 2236);
+    }
+
+    #[test]
+    fn test_bench() {
+        let (result, stats) = bench(|input| input.parse::<u32>().ok(), "42");
+        assert_eq!(result, Some(42));
+        assert!(stats.iterations >= MIN_ITERATIONS);
+        assert!(stats.min <= stats.median);
+    }
+}
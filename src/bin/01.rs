@@ -1,41 +1,37 @@
+use advent_of_code::error::ParseError;
+use advent_of_code::helpers::{groups, scan};
 use std::{cmp::Reverse, collections::BinaryHeap};
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (res, _) = input.lines().fold((0, 0), |(res, acc), line| {
-        if line.is_empty() {
-            (res.max(acc), 0)
-        } else {
-            (res, acc + line.parse::<u32>().unwrap())
-        }
-    });
-    Some(res)
+/// The total calories carried by each elf.
+fn totals(input: &str) -> Result<Vec<u32>, ParseError> {
+    groups(input)
+        .map(|group| {
+            group
+                .iter()
+                .map(|line| scan::field::<u32>(input, line, "u32"))
+                .sum()
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    Ok(totals(input)?.into_iter().max())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
     let mut heap = BinaryHeap::new();
-    let mut local_sum = 0;
-    for line in input.lines() {
-        if line.is_empty() {
-            heap.push(Reverse(local_sum));
-            local_sum = 0;
-        } else {
-            local_sum += line.parse::<u32>().unwrap();
-        }
-        if heap.len() > 3 {
-            heap.pop();
-        }
-    }
-    if local_sum > 0 {
-        heap.push(Reverse(local_sum));
+    for total in totals(input)? {
+        heap.push(Reverse(total));
         if heap.len() > 3 {
             heap.pop();
         }
     }
-    Some(heap.iter().fold(0, |acc, Reverse(i)| acc + i))
+    Ok(heap.iter().fold(0, |acc, Reverse(i)| acc + i))
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
+    let input = &advent_of_code::read_file("inputs", 1)
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -44,15 +40,11 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_one(&input), Some(24000));
-    }
+    advent_of_code::example_tests!(part_one, part_two);
 
     #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_two(&input), Some(45000));
+    fn test_part_one_malformed() {
+        let error = part_one("1000\n2000\n\n3x00\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
     }
 }
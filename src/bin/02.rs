@@ -1,4 +1,6 @@
+use advent_of_code::error::ParseError;
 use std::cmp::Ordering;
+use std::str::FromStr;
 
 #[derive(PartialEq, Clone, Copy)]
 enum LeftSide {
@@ -7,13 +9,15 @@ enum LeftSide {
     C,
 }
 
-impl From<String> for LeftSide {
-    fn from(s: String) -> Self {
-        match s.as_str() {
-            "A" => LeftSide::A,
-            "B" => LeftSide::B,
-            "C" => LeftSide::C,
-            _ => panic!("undefined input"),
+impl FromStr for LeftSide {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(LeftSide::A),
+            "B" => Ok(LeftSide::B),
+            "C" => Ok(LeftSide::C),
+            _ => Err("expected one of A, B, C".to_string()),
         }
     }
 }
@@ -73,13 +77,15 @@ impl RightSide {
     }
 }
 
-impl From<String> for RightSide {
-    fn from(s: String) -> Self {
-        match s.as_str() {
-            "X" => RightSide::X,
-            "Y" => RightSide::Y,
-            "Z" => RightSide::Z,
-            _ => panic!("undefined input"),
+impl FromStr for RightSide {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(RightSide::X),
+            "Y" => Ok(RightSide::Y),
+            "Z" => Ok(RightSide::Z),
+            _ => Err("expected one of X, Y, Z".to_string()),
         }
     }
 }
@@ -130,32 +136,30 @@ impl Game {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
     let mut game = Game::new();
     for line in input.lines() {
-        let pair: Vec<_> = line.split(' ').collect();
-        let left_side = String::from(pair[0]);
-        let right_side = String::from(pair[1]);
-        game.round(left_side.into(), right_side.into())
+        let (left, right) = advent_of_code::scan!(line, "{} {}", LeftSide, RightSide)
+            .map_err(|e| e.within(input, line))?;
+        game.round(left, right)
     }
-    Some(game.total_score())
+    Ok(game.total_score())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
     let mut game = Game::new();
     for line in input.lines() {
-        let pair: Vec<_> = line.split(' ').collect();
-        let left_side = String::from(pair[0]);
-        let right_side = String::from(pair[1]);
-        let left: LeftSide = left_side.into();
-        let right = left.get_according_to_strategy(right_side.into());
+        let (left, strategy) = advent_of_code::scan!(line, "{} {}", LeftSide, RightSide)
+            .map_err(|e| e.within(input, line))?;
+        let right = left.get_according_to_strategy(strategy);
         game.round(left, right);
     }
-    Some(game.total_score())
+    Ok(game.total_score())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
+    let input = &advent_of_code::read_file("inputs", 2)
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -164,15 +168,5 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
-    }
+    advent_of_code::example_tests!(part_one, part_two);
 }
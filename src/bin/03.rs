@@ -21,13 +21,7 @@ fn calculate(s: String) -> u32 {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .into_iter()
-            .map(|i| calculate(i.to_string()))
-            .sum(),
-    )
+    Some(input.lines().map(|i| calculate(i.to_string())).sum())
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
@@ -58,7 +52,8 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
+    let input = &advent_of_code::read_file("inputs", 3)
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -67,15 +62,5 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(157));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(70));
-    }
+    advent_of_code::example_tests!(part_one, part_two);
 }
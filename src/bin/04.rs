@@ -1,3 +1,5 @@
+use advent_of_code::error::ParseError;
+
 struct Range {
     left_bound: u32,
     right_bound: u32,
@@ -14,46 +16,45 @@ impl Range {
     }
 }
 
-impl From<&str> for Range {
-    fn from(line: &str) -> Self {
-        let pair: Vec<_> = line.split('-').collect();
-        Self {
-            left_bound: pair[0].parse::<u32>().unwrap(),
-            right_bound: pair[1].parse::<u32>().unwrap(),
-        }
-    }
+fn parse_line(line: &str) -> Result<(Range, Range), ParseError> {
+    let (a, b, c, d) = advent_of_code::scan!(line, "{}-{},{}-{}", u32, u32, u32, u32)?;
+    Ok((
+        Range {
+            left_bound: a,
+            right_bound: b,
+        },
+        Range {
+            left_bound: c,
+            right_bound: d,
+        },
+    ))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .filter(|line| {
-                let splitted: Vec<_> = line.split(',').collect();
-                let left_range = Range::from(splitted[0]);
-                let right_range = Range::from(splitted[1]);
-                left_range.include(&right_range) || right_range.include(&left_range)
-            })
-            .count() as u32,
-    )
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let mut count = 0;
+    for line in input.lines() {
+        let (left_range, right_range) = parse_line(line).map_err(|e| e.within(input, line))?;
+        if left_range.include(&right_range) || right_range.include(&left_range) {
+            count += 1;
+        }
+    }
+    Ok(count)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .filter(|line| {
-                let splitted: Vec<_> = line.split(',').collect();
-                let left_range = Range::from(splitted[0]);
-                let right_range = Range::from(splitted[1]);
-                left_range.intersect(&right_range) || right_range.intersect(&left_range)
-            })
-            .count() as u32,
-    )
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let mut count = 0;
+    for line in input.lines() {
+        let (left_range, right_range) = parse_line(line).map_err(|e| e.within(input, line))?;
+        if left_range.intersect(&right_range) || right_range.intersect(&left_range) {
+            count += 1;
+        }
+    }
+    Ok(count)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
+    let input = &advent_of_code::read_file("inputs", 4)
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -62,15 +63,11 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_one(&input), Some(2));
-    }
+    advent_of_code::example_tests!(part_one, part_two);
 
     #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_two(&input), Some(4));
+    fn test_part_one_malformed() {
+        let error = part_one("2-4,6-8\n2-4,6-x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
     }
 }
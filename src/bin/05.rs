@@ -1,12 +1,15 @@
+use advent_of_code::error::ParseError;
+use advent_of_code::helpers::groups;
+use advent_of_code::helpers::parse::{ints, keyword, unsigned};
 use std::collections::{HashMap, VecDeque};
 
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{anychar, digit1, space1, u32 as nom_u32},
-    combinator::{map, map_res},
+    character::complete::{anychar, space1},
+    combinator::map,
     multi::separated_list0,
-    sequence::{delimited, tuple},
+    sequence::{delimited, preceded, tuple},
     IResult,
 };
 
@@ -36,122 +39,108 @@ fn parse_line(input: &str) -> IResult<&str, Vec<Option<char>>> {
 }
 
 fn parse_numbers(input: &str) -> IResult<&str, Vec<u32>> {
-    let input = input.trim();
-    separated_list0(space1, map_res(digit1, |i: &str| i.parse()))(input)
+    ints(input.trim())
 }
 
 fn parse_moves(input: &str) -> IResult<&str, Move> {
     map(
         tuple((
-            tag("move "),
-            nom_u32,
-            tag(" from "),
-            nom_u32,
-            tag(" to "),
-            nom_u32,
+            keyword("move", unsigned),
+            preceded(space1, keyword("from", unsigned)),
+            preceded(space1, keyword("to", unsigned)),
         )),
-        |(_, count, _, from, _, to)| Move::from(count, from, to),
+        |(count, from, to)| Move::from(count, from, to),
     )(input)
 }
 
-pub fn part_one(input: &str) -> Option<String> {
+/// The crates of each stack by stack number, bottom to top.
+type Stacks = HashMap<u32, Vec<char>>;
+
+/// Parses the drawing of the stacks, the first group of `input`.
+/// Returns the stack numbers in order along with the stacks.
+fn parse_stacks(input: &str, drawing: &[&str]) -> Result<(Vec<u32>, Stacks), ParseError> {
     let mut raw_buckets: HashMap<u32, VecDeque<char>> = HashMap::new();
-    let mut buckets: HashMap<u32, Vec<char>> = HashMap::new();
-
-    let mut lines_iterator = input.lines();
-    let mut col_numbers = Vec::new();
-    for line in lines_iterator.by_ref() {
-        let (remaining, parsed) = parse_line(line).ok()?;
-        if remaining == line {
-            let (_, numbers) = parse_numbers(line).ok()?;
-            col_numbers = numbers.clone();
-            for (index, number) in numbers.into_iter().enumerate() {
+    let mut buckets = Stacks::new();
+
+    for line in drawing {
+        let (remaining, parsed) = parse_line(line).map_err(|e| ParseError::from_nom(input, e))?;
+        if remaining == *line {
+            let (_, numbers) = parse_numbers(line).map_err(|e| ParseError::from_nom(input, e))?;
+            for (index, number) in numbers.iter().enumerate() {
                 if let Some(v) = raw_buckets.remove(&(index as u32)) {
-                    buckets.insert(number, Vec::from(v));
+                    buckets.insert(*number, Vec::from(v));
                 }
             }
-            break;
-        } else {
-            for (index, ch) in parsed.into_iter().enumerate() {
-                if let Some(v) = ch {
-                    raw_buckets
-                        .entry(index as u32)
-                        .and_modify(|e| e.push_front(v))
-                        .or_insert_with(|| VecDeque::from([v]));
-                }
+            return Ok((numbers, buckets));
+        }
+        for (index, ch) in parsed.into_iter().enumerate() {
+            if let Some(v) = ch {
+                raw_buckets
+                    .entry(index as u32)
+                    .and_modify(|e| e.push_front(v))
+                    .or_insert_with(|| VecDeque::from([v]));
             }
         }
     }
-    lines_iterator.next(); // empty line
 
-    for line in lines_iterator {
-        let (_, mv) = parse_moves(line).ok()?;
-        for _ in 0..mv.count {
-            let v = buckets.get_mut(&mv.from).unwrap().pop().unwrap();
-            buckets.get_mut(&mv.to).unwrap().push(v);
-        }
-    }
+    let end = drawing.last().map_or(input, |line| &line[line.len()..]);
+    Err(ParseError::at(input, end, "expected the stack numbers"))
+}
 
-    let mut res: Vec<char> = Vec::new();
-    for col in col_numbers {
-        let cargo = buckets.get_mut(&col).unwrap().pop().unwrap();
-        res.push(cargo);
+/// Runs the moves on the stacks and returns the top crate of each stack,
+/// or `None` if a stack ends up empty.
+/// `move_crates` moves `count` crates from the first stack to the second one,
+/// returning `None` if the first stack holds fewer crates.
+fn rearrange(
+    input: &str,
+    move_crates: impl Fn(u32, &mut Vec<char>, &mut Vec<char>) -> Option<()>,
+) -> Result<Option<String>, ParseError> {
+    let mut groups = groups(input);
+    let drawing = groups.next().unwrap_or_default();
+    let (col_numbers, mut buckets) = parse_stacks(input, &drawing)?;
+
+    for line in groups.next().unwrap_or_default() {
+        let (_, mv) = parse_moves(line).map_err(|e| ParseError::from_nom(input, e))?;
+        let unknown = |stack| ParseError::at(input, line, format!("unknown stack {}", stack));
+        let mut from = buckets.remove(&mv.from).ok_or_else(|| unknown(mv.from))?;
+        let to = buckets.get_mut(&mv.to).ok_or_else(|| unknown(mv.to))?;
+        move_crates(mv.count, &mut from, to).ok_or_else(|| {
+            ParseError::at(
+                input,
+                line,
+                format!("cannot move {} crates from stack {}", mv.count, mv.from),
+            )
+        })?;
+        buckets.insert(mv.from, from);
     }
 
-    Some(res.iter().collect())
+    Ok(col_numbers
+        .iter()
+        .map(|col| buckets.get(col)?.last().copied())
+        .collect())
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let mut raw_buckets: HashMap<u32, VecDeque<char>> = HashMap::new();
-    let mut buckets: HashMap<u32, Vec<char>> = HashMap::new();
-
-    let mut lines_iterator = input.lines();
-    let mut col_numbers = Vec::new();
-    for line in lines_iterator.by_ref() {
-        let (remaining, parsed) = parse_line(line).ok()?;
-        if remaining == line {
-            let (_, numbers) = parse_numbers(line).ok()?;
-            col_numbers = numbers.clone();
-            for (index, number) in numbers.into_iter().enumerate() {
-                if let Some(v) = raw_buckets.remove(&(index as u32)) {
-                    buckets.insert(number, Vec::from(v));
-                }
-            }
-            break;
-        } else {
-            for (index, ch) in parsed.into_iter().enumerate() {
-                if let Some(v) = ch {
-                    raw_buckets
-                        .entry(index as u32)
-                        .and_modify(|e| e.push_front(v))
-                        .or_insert_with(|| VecDeque::from([v]));
-                }
-            }
+pub fn part_one(input: &str) -> Result<Option<String>, ParseError> {
+    rearrange(input, |count, from, to| {
+        for _ in 0..count {
+            let v = from.pop()?;
+            to.push(v);
         }
-    }
-    lines_iterator.next(); // empty line
-
-    for line in lines_iterator {
-        let (_, mv) = parse_moves(line).ok()?;
-        let mut cur_vec = VecDeque::new();
-        for _ in 0..mv.count {
-            let v = buckets.get_mut(&mv.from).unwrap().pop().unwrap();
-            cur_vec.push_front(v);
-        }
-        buckets.get_mut(&mv.to).unwrap().extend(cur_vec);
-    }
-
-    let mut res: Vec<char> = Vec::new();
-    for col in col_numbers {
-        let cargo = buckets.get_mut(&col).unwrap().pop().unwrap();
-        res.push(cargo);
-    }
+        Some(())
+    })
+}
 
-    Some(res.iter().collect())
+pub fn part_two(input: &str) -> Result<Option<String>, ParseError> {
+    rearrange(input, |count, from, to| {
+        let at = from.len().checked_sub(count as usize)?;
+        to.extend(from.drain(at..));
+        Some(())
+    })
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
+    let input = &advent_of_code::read_file("inputs", 5)
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -160,16 +149,17 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some("CMZ".to_string()));
-    }
+    advent_of_code::example_tests!(part_one, part_two);
 
     #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some("MCD".to_string()));
+    fn test_part_one_malformed() {
+        let error = part_one("[A]\n 1 \n\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.message, "unknown stack 2");
+        let error = part_one("[A]\n 1 \n\nmove x from 1 to 1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 6));
+        let error = part_two("[A] [B]\n 1   2 \n\nmove 2 from 1 to 2\n").unwrap_err();
+        assert_eq!(error.message, "cannot move 2 crates from stack 1");
     }
 
     #[test]
@@ -1,11 +1,15 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
+use advent_of_code::error::ParseError;
+use advent_of_code::helpers::parse::{key_value, keyword, line, parse_all, unsigned};
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{alpha1, line_ending, newline, not_line_ending, space1, u32 as nom_u32},
+    character::complete::{alpha1, not_line_ending, space1},
+    combinator::map,
     multi::many1,
-    sequence::{delimited, pair, preceded, terminated},
+    sequence::{preceded, terminated},
     IResult,
 };
 
@@ -69,20 +73,22 @@ impl From<CD> for Action {
 }
 
 fn parse_file(input: &str) -> IResult<&str, Action> {
-    pair(
-        terminated(nom_u32, space1),
-        terminated(not_line_ending, newline),
+    map(
+        line(key_value(unsigned, not_line_ending)),
+        |(size, name): (u32, &str)| Action::Listing(LS::File(size, name.to_string())),
     )(input)
-    .map(|(rem, (size, name))| (rem, Action::Listing(LS::File(size, name.to_string()))))
 }
 
 fn parse_dir(input: &str) -> IResult<&str, Action> {
-    delimited(tag("dir"), preceded(space1, alpha1), newline)(input)
-        .map(|(rem, name)| (rem, Action::Listing(LS::Dir(name.to_string()))))
+    map(line(keyword("dir", alpha1)), |name: &str| {
+        Action::Listing(LS::Dir(name.to_string()))
+    })(input)
 }
 
 fn parse_ls(input: &str) -> IResult<&str, Action> {
-    delimited(parse_cmd_prefix, tag("ls"), newline)(input).map(|(rem, _)| (rem, Action::LSCommand))
+    map(line(preceded(parse_cmd_prefix, tag("ls"))), |_| {
+        Action::LSCommand
+    })(input)
 }
 
 fn parse_cmd_prefix(input: &str) -> IResult<&str, &str> {
@@ -90,14 +96,13 @@ fn parse_cmd_prefix(input: &str) -> IResult<&str, &str> {
 }
 
 fn parse_cd(input: &str) -> IResult<&str, Action> {
-    terminated(
-        preceded(
-            preceded(parse_cmd_prefix, terminated(tag("cd"), space1)),
-            alt((tag(".."), tag("/"), alpha1)),
-        ),
-        line_ending,
+    map(
+        line(preceded(
+            parse_cmd_prefix,
+            keyword("cd", alt((tag(".."), tag("/"), alpha1))),
+        )),
+        |cd: &str| Action::DirChange(cd.into()),
     )(input)
-    .map(|(rem, cd)| (rem, Action::DirChange(cd.into())))
 }
 
 fn parse(input: &str) -> IResult<&str, Vec<Action>> {
@@ -138,12 +143,11 @@ fn calc_to_delete(root: Rc<RefCell<TreeNode>>, lo_bound: u32, acc: &mut u32) ->
     total
 }
 
-fn build_tree(input: &str) -> Rc<RefCell<TreeNode>> {
+fn build_tree(input: &str) -> Result<Rc<RefCell<TreeNode>>, ParseError> {
     let wrapped_root = Rc::new(RefCell::new(TreeNode::new(NodeType::Dir)));
     let mut stack = vec![wrapped_root.clone()];
 
-    let (rem, actions) = parse(input).unwrap();
-    assert!(rem.is_empty());
+    let actions = parse_all(parse, input)?;
 
     for action in actions.into_iter() {
         match action {
@@ -176,30 +180,31 @@ fn build_tree(input: &str) -> Rc<RefCell<TreeNode>> {
             Action::LSCommand => (),
         }
     }
-    wrapped_root
+    Ok(wrapped_root)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let root = build_tree(input);
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let root = build_tree(input)?;
 
     let mut acc = 0;
     calc_at_most(root, 100000, &mut acc);
-    Some(acc)
+    Ok(acc)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let root = build_tree(input);
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let root = build_tree(input)?;
 
     let mut acc = 0;
     let total_size = calc_at_most(root.clone(), 10000, &mut acc);
     let lo = total_size - (70000000 - 30000000);
     let mut acc = total_size;
     calc_to_delete(root, lo, &mut acc);
-    Some(acc)
+    Ok(acc)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
+    let input = &advent_of_code::read_file("inputs", 7)
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -208,16 +213,12 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95437));
-    }
+    advent_of_code::example_tests!(part_one, part_two);
 
     #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
+    fn test_part_one_malformed() {
+        let error = part_one("$ cd /\n$ ls\nx b.txt\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
     }
 
     #[test]
@@ -1,3 +1,4 @@
+use advent_of_code::error::ParseError;
 use nom::{
     bytes::complete::take,
     character::complete::{line_ending, u8 as nom_u8},
@@ -34,7 +35,7 @@ fn backtrack_visible(
     let (visible, prev_direction) = visibility_table[row][col];
     if visible
         && table[started_row][started_col] > table[row][col]
-        && prev_direction.map_or(false, |v| v == direction)
+        && prev_direction == Some(direction)
     {
         return true;
     }
@@ -104,12 +105,12 @@ fn backtrack_scenic(
     backtrack_scenic(table, started_row, started_col, new_row, new_col, direction)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (_, table) = parse_input(input).unwrap();
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let (_, table) = parse_input(input).map_err(|e| ParseError::from_nom(input, e))?;
     let mut visibility_table: Vec<Vec<(bool, Option<&Direction>)>> =
         vec![vec![(false, None); table[0].len()]; table.len()];
 
-    let directions = vec![
+    let directions = [
         Direction::Down,
         Direction::Up,
         Direction::Right,
@@ -128,13 +129,13 @@ pub fn part_one(input: &str) -> Option<u32> {
             }
         }
     }
-    Some(res)
+    Ok(res)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (_, table) = parse_input(input).unwrap();
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let (_, table) = parse_input(input).map_err(|e| ParseError::from_nom(input, e))?;
 
-    let directions = vec![
+    let directions = [
         Direction::Down,
         Direction::Up,
         Direction::Right,
@@ -150,11 +151,12 @@ pub fn part_two(input: &str) -> Option<u32> {
             max_scenic = max_scenic.max(cur_max);
         }
     }
-    Some(max_scenic)
+    Ok(max_scenic)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
+    let input = &advent_of_code::read_file("inputs", 8)
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -163,17 +165,7 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_one(&input), Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_two(&input), Some(8));
-    }
+    advent_of_code::example_tests!(part_one, part_two);
 
     #[test]
     fn test_parse_input() {
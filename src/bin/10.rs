@@ -1,57 +1,50 @@
 use std::collections::HashSet;
 
-use nom::IResult;
-
-#[derive(PartialEq, Debug)]
+use advent_of_code::error::ParseError;
+use advent_of_code::helpers::parse::{keyword, line, signed};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    IResult,
+};
+
+#[derive(Clone, PartialEq, Debug)]
 enum Token {
     Noop,
     Addx(i32),
 }
 
 fn parse_noop(input: &str) -> IResult<&str, Token> {
-    nom::sequence::terminated(
-        nom::bytes::complete::tag("noop"),
-        nom::combinator::opt(nom::character::complete::line_ending),
-    )(input)
-    .map(|(rem, _)| (rem, Token::Noop))
+    value(Token::Noop, line(tag("noop")))(input)
 }
 
 fn parse_addx(input: &str) -> IResult<&str, Token> {
-    nom::sequence::separated_pair(
-        nom::bytes::complete::tag("addx"),
-        nom::character::complete::space1,
-        nom::sequence::terminated(
-            nom::character::complete::i32,
-            nom::combinator::opt(nom::character::complete::line_ending),
-        ),
-    )(input)
-    .map(|(rem, (_, v))| (rem, Token::Addx(v)))
+    map(line(keyword("addx", signed)), Token::Addx)(input)
 }
 
 fn parse_line(input: &str) -> IResult<&str, Token> {
-    nom::branch::alt((parse_noop, parse_addx))(input)
+    alt((parse_noop, parse_addx))(input)
 }
 
 fn check_pos(x: i32, crt: i32) -> bool {
     HashSet::from([x - 1, x, x + 1]).contains(&crt)
 }
 
-fn print_if_full(chars: &mut Vec<char>) {
+fn push_if_full(chars: &mut Vec<char>, screen: &mut Vec<String>) {
     if chars.len() != 40 {
         return;
     }
-    let line: String = chars.clone().into_iter().collect();
-    dbg!(line);
-    chars.clear();
+    screen.push(chars.drain(..).collect());
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
+pub fn part_one(input: &str) -> Result<i32, ParseError> {
     let mut res = 0;
     let mut x = 1;
     let mut cycle = 0;
     let cycles = HashSet::from([20, 60, 100, 140, 180, 220]);
     for line in input.lines() {
-        let (_, token) = parse_line(line).unwrap();
+        let (_, token) = parse_line(line).map_err(|e| ParseError::from_nom(input, e))?;
         cycle += 1;
         if cycles.contains(&cycle) {
             res += x * cycle;
@@ -67,21 +60,23 @@ pub fn part_one(input: &str) -> Option<i32> {
             }
         }
     }
-    Some(res)
+    Ok(res)
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
+/// The rows of pixels drawn on the CRT.
+fn render(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut screen = Vec::new();
     let mut chars = Vec::new();
     let mut x = 1;
     let mut crt = 0;
     for line in input.lines() {
-        let (_, token) = parse_line(line).unwrap();
+        let (_, token) = parse_line(line).map_err(|e| ParseError::from_nom(input, e))?;
         if check_pos(x, crt) {
             chars.push('#')
         } else {
             chars.push('.')
         }
-        print_if_full(&mut chars);
+        push_if_full(&mut chars, &mut screen);
         crt += 1;
         crt %= 40;
 
@@ -93,7 +88,7 @@ pub fn part_two(input: &str) -> Option<i32> {
                 } else {
                     chars.push('.')
                 }
-                print_if_full(&mut chars);
+                push_if_full(&mut chars, &mut screen);
                 crt += 1;
                 crt %= 40;
                 x += v;
@@ -101,11 +96,19 @@ pub fn part_two(input: &str) -> Option<i32> {
         }
     }
 
-    None
+    Ok(screen)
+}
+
+/// The answer is made of the letters that [`render`] draws. They have to be read off by eye,
+/// so the part is never reported as solved.
+pub fn part_two(input: &str) -> Result<Option<i32>, ParseError> {
+    render(input)?;
+    Ok(None)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
+    let input = &advent_of_code::read_file("inputs", 10)
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -114,16 +117,34 @@ fn main() {
 mod tests {
     use super::*;
 
+    advent_of_code::example_tests!(part_one);
+
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 10).unwrap();
+        assert_eq!(part_two(&input).unwrap(), None);
     }
 
     #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), None);
+    fn test_render() {
+        let input = advent_of_code::read_file("examples", 10).unwrap();
+        assert_eq!(
+            render(&input).unwrap(),
+            vec![
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......####",
+                "#######.......#######.......#######.....",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part_one_malformed() {
+        let error = part_one("noop\naddx 3\naddx x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
     }
 
     #[test]
@@ -0,0 +1,68 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::Client;
+use advent_of_code::{exit_with_error, puzzle, DEFAULT_YEAR};
+
+struct Args {
+    day: u8,
+    year: i16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_str()?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {}", e)),
+    };
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => exit_with_error(format!("Failed to create client: {}", e)),
+    };
+
+    let year = args.year;
+
+    println!("Fetching puzzle for day {}, {}...", args.day, year);
+
+    let html = match puzzle::fetch_and_store(&client, year, args.day) {
+        Ok(html) => html,
+        Err(e) => exit_with_error(format!("Failed to fetch puzzle: {}", e)),
+    };
+
+    println!(
+        "Wrote puzzle description to \"{}\"",
+        puzzle::puzzle_path(year, args.day)
+            .unwrap_or_else(|e| exit_with_error(e))
+            .display()
+    );
+
+    let example_path = advent_of_code::year_input_path(year, "examples", args.day)
+        .unwrap_or_else(|e| exit_with_error(e));
+    match puzzle::store_example(&html, year, args.day) {
+        Ok(true) => println!("Wrote example to \"{}\"", example_path.display()),
+        Ok(false) => println!(
+            "Skipped example, \"{}\" already has content or the puzzle has no example",
+            example_path.display()
+        ),
+        Err(e) => exit_with_error(format!("Failed to write example file: {}", e)),
+    }
+
+    println!("---");
+    println!(
+        "🎄 Read the puzzle in \"{}\".",
+        puzzle::puzzle_path(year, args.day)
+            .unwrap_or_else(|e| exit_with_error(e))
+            .display()
+    );
+}
@@ -0,0 +1,85 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::readme::{self, Row};
+use advent_of_code::verify::{self, Verdict};
+use advent_of_code::{days, exit_with_error, Mode, DEFAULT_YEAR};
+use std::fs;
+
+const README_PATH: &str = "README.md";
+
+struct Args {
+    year: i16,
+    timings: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        timings: args.contains("--timings"),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {}", e)),
+    };
+
+    // timings are benchmarked so they do not jump around between runs.
+    let mode = if args.timings {
+        Mode::Bench
+    } else {
+        Mode::Once
+    };
+
+    let rows: Vec<Row> = (1..=25)
+        .filter(|day| days::get(args.year, *day).is_some())
+        .map(|day| {
+            let expected = verify::read_answers(args.year, day);
+            match days::run(args.year, day, mode) {
+                Ok(solutions) => Row {
+                    day,
+                    stars: [0, 1].map(|i| {
+                        verify::verify(&solutions[i], expected[i].as_deref()) == Verdict::Pass
+                    }),
+                    timings: args
+                        .timings
+                        .then(|| [solutions[0].elapsed, solutions[1].elapsed]),
+                },
+                Err(_) => Row {
+                    day,
+                    stars: [false, false],
+                    timings: None,
+                },
+            }
+        })
+        .collect();
+
+    let readme_path = advent_of_code::project_dir().join(README_PATH);
+    let contents = match fs::read_to_string(&readme_path) {
+        Ok(contents) => contents,
+        Err(e) => exit_with_error(format!("Failed to read readme: {}", e)),
+    };
+
+    let table = readme::render_table(args.year, &rows);
+    let updated = match readme::update(&contents, args.year, &table) {
+        Some(updated) => updated,
+        None => exit_with_error(format!(
+            "Could not find the \"{}\" marker in the readme.",
+            readme::TABLE_MARKER
+        )),
+    };
+
+    match advent_of_code::write_atomic(&readme_path, &updated) {
+        Ok(_) => {
+            println!("---");
+            println!("🎄 Updated results table in \"{}\".", README_PATH);
+        }
+        Err(e) => exit_with_error(format!("Failed to write readme: {}", e)),
+    }
+}
@@ -2,113 +2,228 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::aoc::Client;
+use advent_of_code::template::{self, Placeholders};
+use advent_of_code::{exit_with_error, input, puzzle, DEFAULT_YEAR};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
-    process,
+    path::Path,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    year: i16,
+    template: String,
+    /// Also fetch the puzzle description, the example and the real input.
+    download: bool,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| template::DEFAULT_TEMPLATE.to_string()),
+        download: args.contains("--download"),
+        day: args.free_from_str()?,
+    })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// Binaries outside of `src/bin` are not discovered by cargo and need a `[[bin]]` entry.
+fn register_bin(year: i16, day: u8, module_path: &Path) -> Result<(), std::io::Error> {
+    let project_dir = advent_of_code::project_dir();
+    let relative = module_path.strip_prefix(project_dir).unwrap_or(module_path);
+    let mut manifest = OpenOptions::new()
+        .append(true)
+        .open(project_dir.join("Cargo.toml"))?;
+    write!(
+        manifest,
+        "\n[[bin]]\nname = \"{}\"\npath = \"{}\"\n",
+        advent_of_code::bin_name(year, day),
+        relative.display()
+    )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn has_content(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|contents| !contents.trim().is_empty())
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Fetches the puzzle page and the real input.
+/// Nothing is written here, so a failed request leaves no files behind.
+fn download(year: i16, day: u8) -> (String, String) {
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => exit_with_error(format!("Failed to create client: {}", e)),
+    };
+
+    println!("Downloading puzzle and input for day {}, {}...", day, year);
+    let html = match client.puzzle(year, day) {
+        Ok(html) => html,
+        Err(e) => exit_with_error(format!("Failed to fetch puzzle: {}", e)),
+    };
+
+    match client.input(year, day) {
+        Ok(input) => (html, input),
+        Err(e) => exit_with_error(format!("Failed to download input: {}", e)),
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args {
+        day,
+        year,
+        template,
+        download: should_download,
+    } = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
+            exit_with_error("Need to specify a day (as integer). example: `cargo scaffold 7`")
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    if !(1..=25).contains(&day) {
+        exit_with_error(input::Error::BadDay(day));
+    }
+
+    let template = match template::load(&template) {
+        Ok(template) => template,
+        Err(e) => exit_with_error(format!("Failed to load template: {}", e)),
+    };
+
+    let input_path =
+        advent_of_code::year_input_path(year, "inputs", day).unwrap_or_else(|e| exit_with_error(e));
+    let example_path = advent_of_code::year_input_path(year, "examples", day)
+        .unwrap_or_else(|e| exit_with_error(e));
+    let expected_path = example_path.with_extension("expected");
+    let puzzle_path = puzzle::puzzle_path(year, day).unwrap_or_else(|e| exit_with_error(e));
+    let module_path = advent_of_code::bin_dir(year).join(format!("{:02}.rs", day));
+
+    if module_path.exists() {
+        exit_with_error(format!(
+            "Module file \"{}\" already exists",
+            module_path.display()
+        ));
+    }
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let downloaded = should_download.then(|| download(year, day));
+
+    // the title is known once the puzzle was fetched, either above or with `cargo read`.
+    let title = match &downloaded {
+        Some((html, _)) => puzzle::title(html),
+        None => fs::read_to_string(&puzzle_path)
+            .ok()
+            .and_then(|description| puzzle::title(&description)),
+    }
+    .unwrap_or_default();
+    let module = template::render(&template, &Placeholders { year, day, title });
+
+    for path in [&input_path, &example_path, &module_path] {
+        if let Err(e) = fs::create_dir_all(path.parent().unwrap()) {
+            exit_with_error(format!(
+                "Failed to create directory for \"{}\": {}",
+                path.display(),
+                e
+            ));
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
-        }
+        Err(e) => exit_with_error(format!("Failed to create module file: {}", e)),
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
+        Err(e) => exit_with_error(format!("Failed to write module contents: {}", e)),
+    }
+
+    if let Some((html, _)) = &downloaded {
+        match puzzle::store(html, year, day) {
+            Ok(_) => println!("Wrote puzzle description to \"{}\"", puzzle_path.display()),
+            Err(e) => exit_with_error(format!("Failed to write puzzle description: {}", e)),
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    match &downloaded {
+        Some(_) if has_content(&input_path) => {
+            println!("Kept existing input file \"{}\"", input_path.display());
+        }
+        Some((_, input)) => match advent_of_code::write_atomic(&input_path, input) {
+            Ok(_) => println!("Wrote input to \"{}\"", input_path.display()),
+            Err(e) => exit_with_error(format!("Failed to write input file: {}", e)),
+        },
+        None => match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", input_path.display());
+            }
+            Err(e) => exit_with_error(format!("Failed to create input file: {}", e)),
+        },
+    }
+
+    let has_example = match &downloaded {
+        Some(_) if has_content(&example_path) => {
+            println!("Kept existing example file \"{}\"", example_path.display());
+            true
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
+        Some((html, _)) => match puzzle::store_example(html, year, day) {
+            Ok(written) => {
+                if written {
+                    println!("Wrote example to \"{}\"", example_path.display());
+                }
+                written
+            }
+            Err(e) => exit_with_error(format!("Failed to write example file: {}", e)),
+        },
+        None => false,
+    };
+
+    if !has_example {
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", example_path.display());
+            }
+            Err(e) => exit_with_error(format!("Failed to create example file: {}", e)),
         }
     }
 
-    match create_file(&example_path) {
+    match create_file(&expected_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!(
+                "Created empty expected answers file \"{}\"",
+                expected_path.display()
+            );
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+        Err(e) => exit_with_error(format!("Failed to create expected answers file: {}", e)),
+    }
+
+    if year != DEFAULT_YEAR {
+        match register_bin(year, day, &module_path) {
+            Ok(_) => println!("Registered binary in \"Cargo.toml\""),
+            Err(e) => exit_with_error(format!("Failed to register binary: {}", e)),
         }
     }
 
     println!("---");
     println!(
         "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        advent_of_code::bin_name(year, day)
     );
 }
@@ -0,0 +1,61 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{days, exit_with_error, submit, Mode, DEFAULT_YEAR};
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: i16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) if args.part == 1 || args.part == 2 => args,
+        _ => exit_with_error(
+            "Need to specify a day and a part (as integers). example: `cargo submit 7 1`",
+        ),
+    };
+
+    let solution = match days::run(args.year, args.day, Mode::Once) {
+        Ok(solutions) => solutions[usize::from(args.part) - 1].clone(),
+        Err(e) => exit_with_error(format!(
+            "Failed to run the solution for day {}: {}",
+            args.day, e
+        )),
+    };
+
+    let answer = match solution.answer {
+        Some(answer) => answer,
+        None => exit_with_error(format!(
+            "Part {} is not solved, nothing to submit.",
+            args.part
+        )),
+    };
+
+    println!(
+        "Submitting {} for day {}, part {}...",
+        answer, args.day, args.part
+    );
+
+    match submit::submit_and_record(args.year, args.day, args.part, &answer) {
+        Ok(outcome) if outcome.is_wrong() => exit_with_error(format!("The answer is {}.", outcome)),
+        Ok(outcome) => {
+            println!("---");
+            println!("🎄 The answer is {}.", outcome);
+        }
+        Err(e) => exit_with_error(format!("Failed to submit answer: {}", e)),
+    }
+}
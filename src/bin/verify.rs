@@ -0,0 +1,72 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::days::{self, RunError};
+use advent_of_code::verify::{self, Verdict};
+use advent_of_code::{exit_with_error, input, Mode, ANSI_BOLD, ANSI_RESET, DEFAULT_YEAR};
+
+fn parse_args() -> Result<i16, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or(DEFAULT_YEAR))
+}
+
+fn main() {
+    let year = match parse_args() {
+        Ok(year) => year,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {}", e)),
+    };
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in (1..=25).filter(|day| days::get(year, *day).is_some()) {
+        let expected = verify::read_answers(year, day);
+
+        let verdicts: Vec<Verdict> = match days::run(year, day, Mode::Once) {
+            Ok(solutions) => solutions
+                .iter()
+                .zip(expected.iter())
+                .map(|(solution, expected)| verify::verify(solution, expected.as_deref()))
+                .collect(),
+            // inputs are not checked in, so a fresh clone has none yet.
+            Err(e @ RunError::Input(input::Error::Missing(_) | input::Error::Empty(_))) => {
+                println!("Day {:02}: ❔ missing ({})", day, e);
+                missing += 2;
+                continue;
+            }
+            Err(e) => {
+                println!("Day {:02}: ❌ failed to run ({})", day, e);
+                failed += 2;
+                continue;
+            }
+        };
+
+        for (part, verdict) in verdicts.iter().enumerate() {
+            println!("Day {:02} part {}: {}", day, part + 1, verdict);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Missing => missing += 1,
+            }
+        }
+    }
+
+    println!("---");
+    println!(
+        "{}Passed:{} {}, {}failed:{} {}, {}missing:{} {}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        passed,
+        ANSI_BOLD,
+        ANSI_RESET,
+        failed,
+        ANSI_BOLD,
+        ANSI_RESET,
+        missing
+    );
+
+    if failed > 0 {
+        exit_with_error(format!("{} parts did not pass.", failed));
+    }
+}
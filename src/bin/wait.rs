@@ -0,0 +1,139 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{aoc, exit_with_error, input, DEFAULT_YEAR};
+use std::{
+    env,
+    io::{self, Write},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// Attempts after the unlock before giving up. With the backoff below this spans about five minutes.
+const MAX_ATTEMPTS: u32 = 10;
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+struct Args {
+    day: u8,
+    year: i16,
+    template: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        template: args.opt_value_from_str(["-t", "--template"])?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// Waits 1s, 2s, 4s, ... between attempts, but never longer than [`MAX_BACKOFF`].
+fn backoff(attempt: u32) -> Duration {
+    Duration::from_secs(1 << attempt.min(6)).min(MAX_BACKOFF)
+}
+
+fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        secs % 86400 / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+    match secs / 86400 {
+        0 => clock,
+        days => format!("{}d {}", days, clock),
+    }
+}
+
+/// Sleeps until `unlock`, redrawing a countdown every second.
+fn wait_until(unlock: SystemTime, day: u8) {
+    while let Ok(remaining) = unlock.duration_since(SystemTime::now()) {
+        print!(
+            "\r⏳ Day {} unlocks in {} ",
+            day,
+            format_countdown(remaining + Duration::from_millis(999))
+        );
+        let _ = io::stdout().flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+    println!();
+}
+
+/// Runs `cargo scaffold --download`. The scaffold binary is built next to this one,
+/// so the download starts without waiting for cargo.
+fn scaffold(args: &Args) -> bool {
+    let exe = env::current_exe()
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to locate scaffold: {}", e)));
+    let mut command = Command::new(exe.with_file_name("scaffold"));
+    command
+        .arg(args.day.to_string())
+        .args(["--year", &args.year.to_string(), "--download"])
+        .current_dir(advent_of_code::project_dir());
+    if let Some(template) = &args.template {
+        command.args(["--template", template]);
+    }
+    command.status().is_ok_and(|status| status.success())
+}
+
+/// Makes sure the scaffold binary is up to date before the countdown starts.
+fn build_scaffold() {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command
+        .args(["build", "--quiet", "--bin", "scaffold"])
+        .current_dir(advent_of_code::project_dir());
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if !command.status().is_ok_and(|status| status.success()) {
+        exit_with_error("Failed to build scaffold.");
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            exit_with_error("Need to specify a day (as integer). example: `cargo wait-and-fetch 7`")
+        }
+    };
+
+    if !(1..=25).contains(&args.day) {
+        exit_with_error(input::Error::BadDay(args.day));
+    }
+
+    // fail now rather than at midnight.
+    if let Err(e) = aoc::read_session() {
+        exit_with_error(format!("Failed to create client: {}", e));
+    }
+    let module_path = advent_of_code::bin_dir(args.year).join(format!("{:02}.rs", args.day));
+    if module_path.exists() {
+        exit_with_error(format!(
+            "\"{}\" already exists, use `cargo download` to fetch the input.",
+            module_path.display()
+        ));
+    }
+
+    build_scaffold();
+    wait_until(aoc::unlock_time(args.year, args.day), args.day);
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        if scaffold(&args) {
+            return;
+        }
+        if attempt == MAX_ATTEMPTS {
+            break;
+        }
+        let delay = backoff(attempt - 1);
+        println!("Retrying in {}s...", delay.as_secs());
+        thread::sleep(delay);
+    }
+
+    exit_with_error(format!("Giving up after {} attempts.", MAX_ATTEMPTS));
+}
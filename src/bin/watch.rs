@@ -0,0 +1,180 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{exit_with_error, report, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DEFAULT_YEAR};
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::PathBuf,
+    process::{Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+struct Args {
+    day: u8,
+    year: i16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        day: args.free_from_str()?,
+    })
+}
+
+/// The solution, its input and all of its examples along with their expected answers.
+fn watched_files(year: i16, day: u8) -> Vec<PathBuf> {
+    let prefix = format!("{:02}", day);
+    let mut files = vec![
+        advent_of_code::bin_dir(year).join(format!("{}.rs", prefix)),
+        advent_of_code::year_input_path(year, "inputs", day).unwrap_or_else(|e| exit_with_error(e)),
+    ];
+
+    let examples_dir = advent_of_code::year_dir(year)
+        .unwrap_or_else(|e| exit_with_error(e))
+        .join("examples");
+    if let Ok(entries) = fs::read_dir(examples_dir) {
+        files.extend(entries.flatten().map(|entry| entry.path()).filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+        }));
+    }
+    files.sort();
+    files
+}
+
+/// Modification times of the watched files. Any difference between two snapshots triggers a run.
+fn snapshot(year: i16, day: u8) -> Vec<(PathBuf, Option<SystemTime>)> {
+    watched_files(year, day)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+fn cargo(args: &[&str]) -> Output {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    Command::new(cargo)
+        .args(args)
+        .current_dir(advent_of_code::project_dir())
+        .output()
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to run cargo: {}", e)))
+}
+
+/// Prints the output of a failed cargo command.
+fn print_failure(output: &Output) {
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+}
+
+/// Runs the example tests and, if they pass, the real input.
+/// Returns the answers by part, or `None` if the run failed.
+fn run(year: i16, day: u8) -> Option<HashMap<u8, Option<String>>> {
+    let bin_name = advent_of_code::bin_name(year, day);
+
+    let tests = cargo(&["test", "--quiet", "--bin", &bin_name]);
+    if !tests.status.success() {
+        print_failure(&tests);
+        println!("❌ example tests failed");
+        return None;
+    }
+    println!("✅ example tests passed");
+
+    let (year, day) = (year.to_string(), day.to_string());
+    let solve = cargo(&[
+        "run",
+        "--quiet",
+        "--bin",
+        "advent_of_code",
+        "--",
+        "--year",
+        &year,
+        "--day",
+        &day,
+        "--format",
+        "csv",
+        "--no-history",
+    ]);
+    if !solve.status.success() {
+        print_failure(&solve);
+        println!("❌ failed to run the real input");
+        return None;
+    }
+
+    let csv = String::from_utf8_lossy(&solve.stdout);
+    let answers: HashMap<u8, Option<String>> = report::answers_from_csv(&csv)
+        .into_iter()
+        .map(|(_, part, answer)| (part, answer))
+        .collect();
+    if answers.is_empty() {
+        // `cargo all` reports why a day could not be run on stderr.
+        eprint!("{}", String::from_utf8_lossy(&solve.stderr));
+    }
+    Some(answers)
+}
+
+fn describe(answer: Option<&String>) -> &str {
+    answer.map_or("not solved", |answer| answer.as_str())
+}
+
+/// Prints the answers, marking the ones that changed since the previous run.
+fn print_answers(answers: &HashMap<u8, Option<String>>, previous: &HashMap<u8, Option<String>>) {
+    for part in 1..=2 {
+        let answer = answers.get(&part).and_then(|answer| answer.as_ref());
+        let change = match previous.get(&part) {
+            Some(before) if before.as_ref() == answer => "unchanged".to_string(),
+            Some(before) => format!("was {}", describe(before.as_ref())),
+            None => "new".to_string(),
+        };
+        println!(
+            "Part {}: {} {}({}){}",
+            part,
+            describe(answer),
+            ANSI_ITALIC,
+            change,
+            ANSI_RESET
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            exit_with_error("Need to specify a day (as integer). example: `cargo watch-day 7`")
+        }
+    };
+
+    println!(
+        "👀 Watching day {} of {}, press Ctrl+C to stop.",
+        args.day, args.year
+    );
+
+    let mut last_snapshot = None;
+    let mut previous = HashMap::new();
+
+    loop {
+        let current = snapshot(args.year, args.day);
+        if last_snapshot.as_ref() != Some(&current) {
+            last_snapshot = Some(current);
+
+            println!("---");
+            println!("{}| Day {:02} |{}", ANSI_BOLD, args.day, ANSI_RESET);
+            if let Some(answers) = run(args.year, args.day) {
+                print_answers(&answers, &previous);
+                previous = answers;
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Path of the config file, relative to the project directory.
+pub const CONFIG_PATH: &str = ".aoc/config";
+
+/// Settings read from `.aoc/config`.
+/// The file holds one `key = value` pair per line, lines starting with `#` are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Directory holding the inputs, examples, answers and puzzles of the default year.
+    pub input_dir: Option<PathBuf>,
+}
+
+impl Config {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+            let value = value.trim().trim_matches('"');
+            match key.trim() {
+                "input_dir" => config.input_dir = Some(PathBuf::from(value)),
+                key => return Err(format!("line {}: unknown key \"{}\"", i + 1, key)),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Reads the config file of a project. A missing file yields the default config.
+    pub fn load(project_dir: &Path) -> io::Result<Self> {
+        let path = project_dir.join(CONFIG_PATH);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Config::parse(&contents).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(io::Error::new(
+                e.kind(),
+                format!("{}: {}", path.display(), e),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Config::parse("# inputs are kept out of the repo\ninput_dir = \"../aoc-inputs\"\n"),
+            Ok(Config {
+                input_dir: Some(PathBuf::from("../aoc-inputs"))
+            })
+        );
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+        assert!(Config::parse("input_dir").is_err());
+        assert!(Config::parse("inputs = x").is_err());
+    }
+}
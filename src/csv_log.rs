@@ -0,0 +1,47 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A line of a CSV log such as the benchmark history or the submission log.
+pub trait Row: Display + Sized {
+    /// The first line of the file, naming the columns.
+    const HEADER: &'static str;
+
+    /// `None` if the line is malformed.
+    fn parse(line: &str) -> Option<Self>;
+}
+
+/// Reads the rows of a log. A missing file yields no rows.
+pub fn load<R: Row>(path: impl AsRef<Path>) -> io::Result<Vec<R>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(parse(&contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses the rows of a log, skipping the header and malformed lines.
+pub fn parse<R: Row>(contents: &str) -> Vec<R> {
+    contents
+        .lines()
+        .filter(|line| *line != R::HEADER)
+        .filter_map(R::parse)
+        .collect()
+}
+
+/// Renders the header followed by one line per row.
+pub fn render<R: Row>(rows: &[R]) -> String {
+    let mut contents = format!("{}\n", R::HEADER);
+    rows.iter()
+        .for_each(|row| contents.push_str(&format!("{}\n", row)));
+    contents
+}
+
+pub fn save<R: Row>(path: impl AsRef<Path>, rows: &[R]) -> io::Result<()> {
+    crate::write_atomic(path, &render(rows))
+}
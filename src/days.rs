@@ -0,0 +1,63 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::panic;
+
+use crate::{input, Mode, Solution};
+
+pub type Solver = fn(&str, Mode) -> Solution;
+
+pub struct Day {
+    pub year: i16,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Day {
+    pub fn solve(&self, input: &str, mode: Mode) -> [Solution; 2] {
+        [(self.part_one)(input, mode), (self.part_two)(input, mode)]
+    }
+}
+
+// the solution modules are compiled into the library without their unit tests.
+// those run as part of `cargo test --bin NN` instead, so they are not executed twice.
+#[cfg(not(test))]
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+#[cfg(test)]
+pub const DAYS: &[Day] = &[];
+
+pub fn get(year: i16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    /// There is no solution for the day.
+    NotSolved,
+    Input(input::Error),
+    Panicked,
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::NotSolved => write!(f, "not solved"),
+            RunError::Input(e) => write!(f, "{}", e),
+            RunError::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+/// Runs both parts of a day against its real input.
+pub fn run(year: i16, day: u8, mode: Mode) -> Result<[Solution; 2], RunError> {
+    let solution = get(year, day).ok_or(RunError::NotSolved)?;
+    let input = crate::read_year_file(year, "inputs", day).map_err(RunError::Input)?;
+    // a panicking solution should not abort a run over all days.
+    panic::catch_unwind(|| solution.solve(&input, mode)).map_err(|_| RunError::Panicked)
+}
@@ -0,0 +1,163 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// A parse failure located in the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the failure.
+    pub line: usize,
+    /// 1-based column of the failure, counted in characters.
+    pub column: usize,
+    /// The offending input line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Locates `rest`, the input that was left when parsing failed, within `input`.
+    /// `rest` has to be a slice of `input`, otherwise the error points at the start of the input.
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Converts the error of a nom parser that was run on `input` or a slice of it.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                ParseError::at(input, e.input, format!("expected {:?}", e.code))
+            }
+            nom::Err::Incomplete(_) => {
+                ParseError::at(input, &input[input.len()..], "unexpected end of input")
+            }
+        }
+    }
+
+    /// Moves an error located in `part`, a slice of `input`, to the same place in `input`,
+    /// e.g. the error of parsing a single line to its position in the whole input.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let start = ParseError::at(input, part, "");
+        if self.line == 1 {
+            ParseError {
+                line: start.line,
+                column: start.column + self.column - 1,
+                text: start.text,
+                message: self.message,
+            }
+        } else {
+            ParseError {
+                line: start.line + self.line - 1,
+                ..self
+            }
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "  | {}", self.text)?;
+        write!(f, "  | {:>width$}", "^", width = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Renders an error followed by all of its sources, one per line.
+pub fn chain(error: &dyn Error) -> String {
+    let mut rendered = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        rendered.push_str(&format!("\ncaused by: {}", cause));
+        source = cause.source();
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::digit1;
+
+    #[test]
+    fn test_at() {
+        let input = "1\n22\n3x3\n";
+        let error = ParseError::at(input, &input[6..], "expected digit");
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 2);
+        assert_eq!(error.text, "3x3");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 2: expected digit\n  | 3x3\n  |  ^"
+        );
+
+        let error = ParseError::at(input, "elsewhere", "");
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    #[test]
+    fn test_from_nom() {
+        let input = "12\nab";
+        let line = input.lines().nth(1).unwrap();
+        let error =
+            ParseError::from_nom(input, digit1::<_, nom::error::Error<_>>(line).unwrap_err());
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "expected Digit");
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "12\nab cd";
+        let line = input.lines().nth(1).unwrap();
+        let error =
+            ParseError::at(&line[3..], &line[4..], "expected digit").within(input, &line[3..]);
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.text, "ab cd");
+    }
+
+    #[test]
+    fn test_chain() {
+        #[derive(Debug)]
+        struct Outer(ParseError);
+        impl Display for Outer {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "invalid instruction")
+            }
+        }
+        impl Error for Outer {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let error = Outer(ParseError::at("x", "x", "expected digit"));
+        assert_eq!(
+            chain(&error),
+            "invalid instruction\ncaused by: line 1, column 1: expected digit\n  | x\n  | ^"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+
+use crate::{verify, Answer};
+
+/// An example input along with the answers it is expected to produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The file stem, e.g. `09` or `09-larger`.
+    pub name: String,
+    pub input: String,
+    pub expected: [Option<String>; 2],
+}
+
+/// Reads all examples of a day: `examples/NN.txt` and any named ones like `examples/NN-larger.txt`.
+/// Expected answers are read from a sidecar file with the extension `.expected`,
+/// in the same format as the answers read by [`verify::read_answers`].
+pub fn read_examples(year: i16, day: u8) -> io::Result<Vec<Example>> {
+    let prefix = format!("{:02}", day);
+    let mut examples = vec![];
+
+    for entry in fs::read_dir(crate::year_dir(year)?.join("examples"))? {
+        let path = entry?.path();
+        let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(name) if path.extension().is_some_and(|ext| ext == "txt") => name.to_string(),
+            _ => continue,
+        };
+        if name != prefix && !name.starts_with(&format!("{}-", prefix)) {
+            continue;
+        }
+
+        let expected = fs::read_to_string(path.with_extension("expected"))
+            .map(|contents| verify::parse_answers(&contents))
+            .unwrap_or_default();
+        examples.push(Example {
+            name,
+            input: fs::read_to_string(&path)?,
+            expected,
+        });
+    }
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Runs `solver` on every example that has an expected answer for `part`
+/// and describes each example whose answer does not match.
+pub fn mismatches<A: Answer>(
+    examples: &[Example],
+    part: u8,
+    solver: impl Fn(&str) -> A,
+) -> Vec<String> {
+    examples
+        .iter()
+        .filter_map(|example| {
+            let expected = example.expected[usize::from(part) - 1].as_deref()?;
+            let actual = match solver(&example.input).into_answer() {
+                Ok(Some(answer)) if answer == expected => return None,
+                Ok(Some(answer)) => answer,
+                Ok(None) => "not solved".to_string(),
+                Err(error) => format!("error: {}", error),
+            };
+            Some(format!(
+                "example {} part {}: expected {}, got {}",
+                example.name, part, expected, actual
+            ))
+        })
+        .collect()
+}
+
+/// Checks a solver against the examples of the binary it is compiled into, see [`crate::example_tests`].
+/// Fails if none of the examples has an expected answer for `part`.
+#[track_caller]
+pub fn check<A: Answer>(bin_name: Option<&str>, part: u8, solver: impl Fn(&str) -> A) {
+    let (year, day) = bin_name
+        .and_then(crate::parse_bin_name)
+        .expect("example tests can only run in a solution binary");
+    let examples = read_examples(year, day)
+        .unwrap_or_else(|e| panic!("could not read examples of day {}: {}", day, e));
+
+    let index = usize::from(part) - 1;
+    assert!(
+        examples
+            .iter()
+            .any(|example| example.expected[index].is_some()),
+        "no expected answer for part {}",
+        part
+    );
+
+    let mismatches = mismatches(&examples, part, solver);
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(name: &str, input: &str, expected: [Option<&str>; 2]) -> Example {
+        Example {
+            name: name.to_string(),
+            input: input.to_string(),
+            expected: expected.map(|answer| answer.map(str::to_string)),
+        }
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let examples = [
+            example("01", "4", [Some("4"), None]),
+            example("01-larger", "15", [Some("16"), Some("15")]),
+            example("01-broken", "x", [Some("1"), None]),
+        ];
+        let solver = |input: &str| input.parse::<u32>();
+
+        assert_eq!(
+            mismatches(&examples, 1, solver),
+            vec![
+                "example 01-larger part 1: expected 16, got 15".to_string(),
+                "example 01-broken part 1: expected 1, got error: invalid digit found in string"
+                    .to_string(),
+            ]
+        );
+        assert!(mismatches(&examples, 2, solver).is_empty());
+        assert_eq!(
+            mismatches(&examples, 2, |_| None::<u32>),
+            vec!["example 01-larger part 2: expected 15, got not solved".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_read_examples() {
+        let examples = read_examples(crate::DEFAULT_YEAR, 1).unwrap();
+        assert_eq!(examples[0].name, "01");
+        assert!(examples[0].input.starts_with("1000"));
+    }
+}
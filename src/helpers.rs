@@ -2,3 +2,14 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod grid;
+pub mod groups;
+pub mod parse;
+pub mod point;
+pub mod scan;
+pub mod search;
+
+pub use grid::Grid;
+pub use groups::groups;
+pub use point::{Direction, Direction8, Point2, Point3};
+pub use search::{astar, bfs, dijkstra};
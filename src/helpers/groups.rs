@@ -0,0 +1,52 @@
+//! Splitting the input into groups of lines separated by blank lines.
+
+use std::str::Lines;
+
+/// Splits `input` into groups of lines separated by blank lines, e.g. the inventories of the elves
+/// on day 1. Line endings are stripped, `\r\n` included, and runs of blank lines as well as blank
+/// lines at the start or end of the input never produce an empty group.
+pub fn groups(input: &str) -> Groups<'_> {
+    Groups {
+        lines: input.lines(),
+    }
+}
+
+/// Iterator returned by [`groups`].
+#[derive(Debug, Clone)]
+pub struct Groups<'a> {
+    lines: Lines<'a>,
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group = vec![];
+        for line in self.lines.by_ref() {
+            if !line.trim().is_empty() {
+                group.push(line);
+            } else if !group.is_empty() {
+                break;
+            }
+        }
+        (!group.is_empty()).then_some(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groups() {
+        assert_eq!(
+            groups("\n1\n2\n\n\n3\n").collect::<Vec<_>>(),
+            vec![vec!["1", "2"], vec!["3"]]
+        );
+        assert_eq!(
+            groups("1\r\n\r\n  2\r\n3").collect::<Vec<_>>(),
+            vec![vec!["1"], vec!["  2", "3"]]
+        );
+        assert_eq!(groups("\n\n").count(), 0);
+    }
+}
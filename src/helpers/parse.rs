@@ -0,0 +1,163 @@
+//! Small nom parsers for formats that come up in many puzzles.
+
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, none_of, one_of, space0, space1},
+    combinator::{map_opt, map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::{many1, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated},
+    IResult, Parser,
+};
+
+use super::Grid;
+use crate::error::ParseError;
+
+/// Runs `parser` on the whole `input`. Trailing whitespace is ignored, anything else that is
+/// left over is an error.
+pub fn parse_all<'a, O>(
+    mut parser: impl Parser<&'a str, O, Error<&'a str>>,
+    input: &'a str,
+) -> Result<O, ParseError> {
+    let (rest, output) = parser
+        .parse(input)
+        .map_err(|e| ParseError::from_nom(input, e))?;
+    if rest.trim().is_empty() {
+        Ok(output)
+    } else {
+        // point at the leftover token, not at the line ending in front of it.
+        Err(ParseError::at(input, rest.trim_start(), "unexpected input"))
+    }
+}
+
+/// An unsigned integer such as `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// An integer with an optional sign such as `-7` or `+3`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// Integers separated by commas and/or spaces, e.g. `1,2,3` or `4 -5  6`.
+pub fn ints<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(alt((recognize(pair(char(','), space0)), space1)), signed)(input)
+}
+
+/// A line parsed by `parser`, including its line ending if there is one.
+pub fn line<'a, O>(
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    terminated(parser, opt(line_ending))
+}
+
+/// Lines parsed by `parser`. The line ending after the last line is not consumed,
+/// so `lines` can be nested in [`blocks`].
+pub fn lines<'a, O>(
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, parser)
+}
+
+/// Blocks parsed by `parser` that are separated by a blank line.
+pub fn blocks<'a, O>(
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), parser)
+}
+
+/// A key and its value separated by spaces, e.g. `move 3` or `dir a`.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, Error<&'a str>>,
+    value: impl Parser<&'a str, V, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(key, space1, value)
+}
+
+/// A fixed `word` followed by spaces and a value, e.g. `keyword("addx", signed)` for `addx -5`.
+pub fn keyword<'a, V>(
+    word: &'static str,
+    value: impl Parser<&'a str, V, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, V> {
+    preceded(pair(tag(word), space1), value)
+}
+
+/// Consecutive lines of the same length, converting each character with `cell`.
+/// Stops at a blank line or a character that `cell` rejects.
+pub fn grid<'a, T>(
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    move |input| {
+        let (rest, rows) =
+            separated_list1(line_ending, many1(map_opt(none_of("\r\n"), &mut cell)))(input)?;
+        let width = rows[0].len();
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(nom::Err::Failure(Error::new(input, ErrorKind::Verify)));
+        }
+        let grid = Grid::from_cells(width, rows.into_iter().flatten().collect())
+            .expect("rows have the same length");
+        Ok((rest, grid))
+    }
+}
+
+/// A grid of characters.
+pub fn char_grid(input: &str) -> IResult<&str, Grid<char>> {
+    grid(Some)(input)
+}
+
+/// A grid of single digits such as a height map.
+pub fn digit_grid(input: &str) -> IResult<&str, Grid<u8>> {
+    grid(|c| c.to_digit(10).map(|digit| digit as u8))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::alpha1;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(unsigned::<u32>("42 rest"), Ok((" rest", 42)));
+        assert!(unsigned::<u32>("-42").is_err());
+        assert_eq!(signed::<i64>("-42"), Ok(("", -42)));
+        assert_eq!(ints::<i32>("1,2, -3  4\n"), Ok(("\n", vec![1, 2, -3, 4])));
+    }
+
+    #[test]
+    fn test_structure() {
+        let input = "a 1\nb 2\n\nc 3\n";
+        assert_eq!(
+            parse_all(blocks(lines(key_value(alpha1, unsigned::<u8>))), input),
+            Ok(vec![vec![("a", 1), ("b", 2)], vec![("c", 3)]])
+        );
+        assert_eq!(
+            parse_all(
+                many1(line(keyword("addx", signed::<i32>))),
+                "addx 1\naddx -2"
+            ),
+            Ok(vec![1, -2])
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let (rest, grid) = digit_grid("123\n456\n\nxyz").unwrap();
+        assert_eq!(
+            (rest, grid.to_string()),
+            ("\n\nxyz", "123\n456".to_string())
+        );
+        assert!(char_grid("ab\nc\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let err = parse_all(lines(unsigned::<u32>), "1\n2\n3x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 2));
+        let err = parse_all(lines(unsigned::<u32>), "1\nx\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,317 @@
+//! Integer points and directions in two and three dimensions.
+
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::grid::Pos;
+
+/// A point or vector in 2D. `y` grows downwards, like the rows of the puzzle input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point or vector in 3D.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// Implements the component-wise operators shared by both point types.
+macro_rules! impl_point {
+    ($point:ident { $($field:ident),+ }) => {
+        impl $point {
+            pub const ORIGIN: Self = $point { $($field: 0),+ };
+
+            pub const fn new($($field: i64),+) -> Self {
+                $point { $($field),+ }
+            }
+
+            /// The sum of the absolute differences of all coordinates.
+            pub fn manhattan(self, other: Self) -> i64 {
+                0 $(+ (self.$field - other.$field).abs())+
+            }
+
+            /// The largest absolute difference of any coordinate, i.e. the number of king moves.
+            pub fn chebyshev(self, other: Self) -> i64 {
+                0 $(.max((self.$field - other.$field).abs()))+
+            }
+
+            /// Replaces every coordinate with its sign.
+            pub fn signum(self) -> Self {
+                $point { $($field: self.$field.signum()),+ }
+            }
+
+            /// Moves at most one unit along every axis towards `other`.
+            pub fn step_towards(self, other: Self) -> Self {
+                self + (other - self).signum()
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl Point2 {
+    /// The 4 points sharing an edge with this one.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    /// The 8 points around this one, including the diagonal ones.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    /// The grid position of this point, or `None` if a coordinate is negative.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl Point3 {
+    /// The 6 points sharing a face with this one.
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+impl From<Pos> for Point2 {
+    fn from((x, y): Pos) -> Self {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// One of the 4 directions along the axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting with up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The unit vector pointing in this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+
+    /// Parses `U`/`R`/`D`/`L` and `^`/`>`/`v`/`<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+/// One of the 8 directions including the diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting with up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns clockwise by 45 degrees.
+    pub fn rotate_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns counterclockwise by 45 degrees.
+    pub fn rotate_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The vector pointing in this direction, with a length of 1 along each axis it moves on.
+    pub fn offset(self) -> Point2 {
+        let (x, y) = super::grid::ALL_DIRECTIONS[self as usize];
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+impl Add<Direction> for Point2 {
+    type Output = Self;
+
+    fn add(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+}
+
+impl Add<Direction8> for Point2 {
+    type Output = Self;
+
+    fn add(self, dir: Direction8) -> Self {
+        self + dir.offset()
+    }
+}
+
+impl AddAssign<Direction> for Point2 {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 5);
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(a + b, Point2::new(-2, 3));
+        assert_eq!((b - a) * 2, Point2::new(-8, 14));
+        assert_eq!(a.step_towards(b), Point2::new(0, -1));
+
+        let c = Point3::new(1, 2, 3);
+        assert_eq!(c.manhattan(Point3::ORIGIN), 6);
+        assert_eq!(c.chebyshev(-c), 6);
+        assert_eq!(c.neighbors6().count(), 6);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Direction8::Up.rotate_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::from(Direction::Down), Direction8::Down);
+        assert_eq!(Point2::ORIGIN + Direction8::DownLeft, Point2::new(-1, 1));
+        assert_eq!(
+            Point2::new(1, 0)
+                .neighbors4()
+                .filter_map(Point2::to_pos)
+                .count(),
+            3
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Pattern based parsing used by the [`scan!`](crate::scan) macro.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// Splits `input` into the parts matching the `{}` placeholders of `pattern`, see [`crate::scan`].
+/// Everything else in `pattern` has to match literally. A placeholder extends up to the first
+/// occurrence of the text that follows it, or to the end of the input for a trailing placeholder.
+pub fn fields<'a>(input: &'a str, pattern: &str, count: usize) -> Result<Vec<&'a str>, ParseError> {
+    let mut literals = pattern.split("{}");
+    assert_eq!(
+        pattern.matches("{}").count(),
+        count,
+        "scan!: the pattern {:?} needs one type per placeholder",
+        pattern
+    );
+
+    let first = literals.next().unwrap_or_default();
+    let mut rest = input
+        .strip_prefix(first)
+        .ok_or_else(|| ParseError::at(input, input, format!("expected {:?}", first)))?;
+
+    let mut fields = vec![];
+    for literal in literals {
+        let end = if literal.is_empty() {
+            assert!(
+                fields.len() + 1 == count,
+                "scan!: placeholders in {:?} need text between them",
+                pattern
+            );
+            rest.len()
+        } else {
+            rest.find(literal).ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    &rest[rest.len()..],
+                    format!("expected {:?}", literal),
+                )
+            })?
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    if rest.is_empty() {
+        Ok(fields)
+    } else {
+        Err(ParseError::at(input, rest, "unexpected input"))
+    }
+}
+
+/// Parses a field returned by [`fields`], pointing at it in `input` if that fails.
+/// `name` is the type as written in the call, e.g. `u32`, and is used in the error message.
+pub fn field<T>(input: &str, field: &str, name: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field
+        .parse()
+        .map_err(|e| ParseError::at(input, field, format!("invalid {} {:?}: {}", name, field, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scan;
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            scan!("2-4,6-8", "{}-{},{}-{}", u32, u32, u32, u32),
+            Ok((2, 4, 6, 8))
+        );
+        assert_eq!(
+            scan!(
+                "move 3 from a to b",
+                "move {} from {} to {}",
+                usize,
+                char,
+                String
+            ),
+            Ok((3, 'a', "b".to_string()))
+        );
+
+        let err = scan!("2-x,6-8", "{}-{},{}-{}", u32, u32, u32, u32).unwrap_err();
+        assert_eq!(err.column, 3);
+        assert!(err.message.starts_with("invalid u32 \"x\""));
+        let err = scan!("0", "{}", std::num::NonZeroU8).unwrap_err();
+        assert!(err.message.starts_with("invalid std::num::NonZeroU8 \"0\""));
+        let err = scan!("2-4", "{}-{},{}-{}", u32, u32, u32, u32).unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (4, "expected \",\""));
+        let err = scan!("x2-4", "{}-{}", u8, u8).unwrap_err();
+        assert_eq!(err.column, 1);
+        let err = scan!("[1]!", "[{}]", u8).unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (4, "unexpected input"));
+    }
+}
@@ -0,0 +1,224 @@
+//! Breadth-first, Dijkstra and A* search over graphs given by a neighbor function.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The distances of all nodes reached by a search, along with the node each was reached from.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    pub distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Search {
+            distances: HashMap::from([(start, zero)]),
+            parents: HashMap::new(),
+        }
+    }
+
+    /// The distance from the start to `node`, or `None` if it was not reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// The shortest path from the start to `node`, including both.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Explores every node reachable from `start`, counting each step as 1.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distances[&node] + 1;
+        for next in neighbors(&node) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// A node in the queue, ordered by its priority only so nodes do not need to implement `Ord`.
+struct Queued<C, N> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<C: Ord, N> PartialEq for Queued<C, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<C: Ord, N> Eq for Queued<C, N> {}
+
+impl<C: Ord, N> PartialOrd for Queued<C, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord, N> Ord for Queued<C, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// Explores every node reachable from `start`. `neighbors` yields each neighbor along with
+/// the cost of moving there, which must not be negative.
+pub fn dijkstra<N, C, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::from([Reverse(Queued {
+        priority: C::default(),
+        cost: C::default(),
+        node: start,
+    })]);
+
+    while let Some(Reverse(Queued { cost, node, .. })) = queue.pop() {
+        if search.distances[&node] < cost {
+            continue;
+        }
+        for (next, step) in neighbors(&node) {
+            let cost = cost + step;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= cost)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), cost);
+            search.parents.insert(next.clone(), node.clone());
+            queue.push(Reverse(Queued {
+                priority: cost,
+                cost,
+                node: next,
+            }));
+        }
+    }
+    search
+}
+
+/// Finds the cheapest path from `start` to a node for which `is_goal` holds.
+/// `heuristic` estimates the remaining cost and must never overestimate it,
+/// e.g. the Manhattan distance to the goal on a grid.
+/// Returns the path, including `start` and the goal, and its cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::from([Reverse(Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    })]);
+
+    while let Some(Reverse(Queued { cost, node, .. })) = queue.pop() {
+        if is_goal(&node) {
+            return Some((search.path(&node)?, cost));
+        }
+        if search.distances[&node] < cost {
+            continue;
+        }
+        for (next, step) in neighbors(&node) {
+            let cost = cost + step;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= cost)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), cost);
+            search.parents.insert(next.clone(), node.clone());
+            queue.push(Reverse(Queued {
+                priority: cost + heuristic(&next),
+                cost,
+                node: next,
+            }));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::Grid;
+
+    const MAZE: &str = "S.#.\n..#E\n....\n";
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::parse(MAZE, Some).unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+        let search = bfs(start, |&pos| {
+            grid.neighbors4(pos).filter(|&p| grid[p] != '#')
+        });
+
+        assert_eq!(search.distance(&end), Some(6));
+        assert_eq!(search.distance(&(2, 0)), None);
+        let path = search.path(&end).unwrap();
+        assert_eq!((path[0], path[path.len() - 1], path.len()), (start, end, 7));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // moving right is expensive, so the cheapest path goes around it.
+        let neighbors = |&(x, y): &(i32, i32)| {
+            [((x + 1, y), 10), ((x, y + 1), 1), ((x + 1, y + 1), 1)]
+                .into_iter()
+                .filter(|((x, y), _)| *x <= 2 && *y <= 2)
+        };
+
+        let search = dijkstra((0, 0), neighbors);
+        assert_eq!(search.distance(&(2, 2)), Some(2));
+        assert_eq!(search.distance(&(2, 0)), Some(20));
+
+        let (path, cost) = astar(
+            (0, 0),
+            neighbors,
+            |&(x, y)| (2 - x).max(2 - y),
+            |&node| node == (2, 2),
+        )
+        .unwrap();
+        assert_eq!((path, cost), (vec![(0, 0), (1, 1), (2, 2)], 2));
+        assert!(astar((0, 0), neighbors, |_| 0, |_| false).is_none());
+    }
+}
@@ -0,0 +1,332 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::csv_log::{self, Row};
+use crate::{Mode, Solution};
+
+/// Location of the benchmark history, relative to the project directory.
+pub const HISTORY_PATH: &str = ".bench/history.csv";
+
+pub fn history_path() -> PathBuf {
+    crate::project_dir().join(HISTORY_PATH)
+}
+
+/// A single recorded timing, keyed by commit, build profile, run mode, year, day and part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub commit: String,
+    pub profile: String,
+    pub mode: Mode,
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+impl Row for Record {
+    const HEADER: &'static str = "commit,profile,mode,year,day,part,elapsed_ns";
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split(',');
+        let record = Record {
+            commit: fields.next()?.to_string(),
+            profile: fields.next()?.to_string(),
+            mode: fields.next()?.parse().ok()?,
+            year: fields.next()?.parse().ok()?,
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            elapsed: Duration::from_nanos(fields.next()?.parse().ok()?),
+        };
+        match fields.next() {
+            None => Some(record),
+            Some(_) => None,
+        }
+    }
+}
+
+impl Record {
+    fn same_key(&self, other: &Record) -> bool {
+        self.commit == other.commit
+            && self.profile == other.profile
+            && self.mode == other.mode
+            && self.year == other.year
+            && self.day == other.day
+            && self.part == other.part
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{}",
+            self.commit,
+            self.profile,
+            self.mode,
+            self.year,
+            self.day,
+            self.part,
+            self.elapsed.as_nanos()
+        )
+    }
+}
+
+/// Timings of previous runs, oldest first.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    /// A missing history file yields an empty history. Malformed lines are skipped.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(History {
+            records: csv_log::load(path)?,
+        })
+    }
+
+    #[cfg(test)]
+    fn parse(contents: &str) -> Self {
+        History {
+            records: csv_log::parse(contents),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        csv_log::save(path, &self.records)
+    }
+
+    /// Adds a record, replacing an earlier one for the same commit, profile, mode, year, day and part.
+    pub fn record(&mut self, record: Record) {
+        self.records.retain(|r| !r.same_key(&record));
+        self.records.push(record);
+    }
+
+    /// The most recent timing of a part that was recorded with the same profile and mode
+    /// at a different commit. Single runs are too noisy to compare against benchmarks.
+    pub fn baseline(
+        &self,
+        commit: &str,
+        profile: &str,
+        mode: Mode,
+        year: i16,
+        day: u8,
+        part: u8,
+    ) -> Option<&Record> {
+        self.records.iter().rev().find(|r| {
+            r.commit != commit
+                && r.profile == profile
+                && r.mode == mode
+                && r.year == year
+                && r.day == day
+                && r.part == part
+        })
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", csv_log::render(&self.records))
+    }
+}
+
+/// A part that got slower compared to its baseline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Record,
+    pub elapsed: Duration,
+}
+
+impl Regression {
+    pub fn slowdown_percent(&self) -> f64 {
+        (self.elapsed.as_secs_f64() / self.baseline.elapsed.as_secs_f64() - 1_f64) * 100_f64
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {:02} part {}: {:.2?} -> {:.2?} (+{:.1}% since {})",
+            self.year,
+            self.day,
+            self.part,
+            self.baseline.elapsed,
+            self.elapsed,
+            self.slowdown_percent(),
+            self.baseline.commit
+        )
+    }
+}
+
+/// Returns all solved parts that are more than `threshold_percent` slower than their baseline.
+pub fn find_regressions(
+    history: &History,
+    solutions: &[Solution],
+    commit: &str,
+    profile: &str,
+    threshold_percent: f64,
+) -> Vec<Regression> {
+    solutions
+        .iter()
+        .filter(|solution| solution.is_solved())
+        .filter_map(|solution| {
+            let baseline = history.baseline(
+                commit,
+                profile,
+                solution.mode(),
+                solution.year,
+                solution.day,
+                solution.part,
+            )?;
+            let regression = Regression {
+                year: solution.year,
+                day: solution.day,
+                part: solution.part,
+                baseline: baseline.clone(),
+                elapsed: solution.elapsed,
+            };
+            if regression.slowdown_percent() > threshold_percent {
+                Some(regression)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// The short hash of `HEAD`, suffixed with `-dirty` if there are uncommitted changes.
+/// Falls back to `unknown` if git is not available.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(crate::project_dir())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// Timings of debug and release builds are not comparable, so they are recorded separately.
+pub fn current_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, day: u8, part: u8, micros: u64) -> Record {
+        Record {
+            commit: commit.to_string(),
+            profile: "release".to_string(),
+            mode: Mode::Once,
+            year: 2022,
+            day,
+            part,
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    fn solution(day: u8, part: u8, micros: u64) -> Solution {
+        Solution::for_test(day, part, Some("0"), Duration::from_micros(micros))
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let mut history = History::default();
+        history.record(record("abc1234", 1, 1, 10));
+        history.record(record("abc1234-dirty", 1, 2, 20));
+
+        let serialized = history.to_string();
+        assert_eq!(
+            serialized,
+            "commit,profile,mode,year,day,part,elapsed_ns\nabc1234,release,once,2022,1,1,10000\nabc1234-dirty,release,once,2022,1,2,20000\n"
+        );
+        assert_eq!(History::parse(&serialized), history);
+        assert_eq!(History::parse("garbage\n1,2\n"), History::default());
+    }
+
+    #[test]
+    fn test_record_replaces_same_key() {
+        let mut history = History::default();
+        history.record(record("abc1234", 1, 1, 10));
+        history.record(record("abc1234", 1, 1, 12));
+        assert_eq!(history.records, vec![record("abc1234", 1, 1, 12)]);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let mut history = History::default();
+        history.record(record("aaaaaaa", 1, 1, 10));
+        history.record(record("bbbbbbb", 1, 1, 20));
+        history.record(record("ccccccc", 1, 1, 30));
+
+        assert_eq!(
+            history.baseline("ccccccc", "release", Mode::Once, 2022, 1, 1),
+            Some(&record("bbbbbbb", 1, 1, 20))
+        );
+        assert_eq!(
+            history.baseline("ddddddd", "release", Mode::Once, 2022, 1, 1),
+            Some(&record("ccccccc", 1, 1, 30))
+        );
+        assert_eq!(
+            history.baseline("ddddddd", "debug", Mode::Once, 2022, 1, 1),
+            None
+        );
+        assert_eq!(
+            history.baseline("ddddddd", "release", Mode::Bench, 2022, 1, 1),
+            None
+        );
+        assert_eq!(
+            history.baseline("ddddddd", "release", Mode::Once, 2022, 1, 2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let mut history = History::default();
+        history.record(record("aaaaaaa", 1, 1, 100));
+        history.record(record("aaaaaaa", 1, 2, 100));
+
+        let solutions = [
+            solution(1, 1, 105),
+            solution(1, 2, 150),
+            solution(2, 1, 999),
+        ];
+        let regressions = find_regressions(&history, &solutions, "bbbbbbb", "release", 10_f64);
+
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].day, 1);
+        assert_eq!(regressions[0].part, 2);
+        assert_eq!(
+            regressions[0].to_string(),
+            "2022 day 01 part 2: 100.00µs -> 150.00µs (+50.0% since aaaaaaa)"
+        );
+    }
+}
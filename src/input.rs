@@ -0,0 +1,72 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Days are numbered from 1 to 25.
+    BadDay(u8),
+    Missing(PathBuf),
+    /// The input file exists but holds nothing, e.g. because it was scaffolded and never downloaded.
+    Empty(PathBuf),
+    Io(PathBuf, io::Error),
+    /// `.aoc/config` could not be read, so it is unknown where the inputs are.
+    Config(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::BadDay(day) => write!(f, "invalid day {}, expected 1 to 25", day),
+            Error::Missing(path) => write!(f, "input missing at \"{}\"", path.display()),
+            Error::Empty(path) => write!(f, "input empty at \"{}\"", path.display()),
+            Error::Io(path, e) => write!(f, "could not read \"{}\": {}", path.display(), e),
+            Error::Config(e) => write!(f, "invalid config: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Io(_, e) | Error::Config(e) => e,
+            e => io::Error::other(e),
+        }
+    }
+}
+
+/// Reads a file of a day from `folder`, e.g. `inputs` or `examples`.
+/// Only inputs have to hold content, examples stay empty until they are filled in by hand.
+pub fn read(year: i16, folder: &str, day: u8) -> Result<String, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::BadDay(day));
+    }
+
+    let path = crate::year_input_path(year, folder, day)?;
+    match fs::read_to_string(&path) {
+        Ok(contents) if folder == "inputs" && contents.trim().is_empty() => Err(Error::Empty(path)),
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(Error::Missing(path)),
+        Err(e) => Err(Error::Io(path, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() {
+        assert!(matches!(read(2022, "inputs", 0), Err(Error::BadDay(0))));
+        assert!(matches!(read(2022, "inputs", 26), Err(Error::BadDay(26))));
+        assert!(matches!(read(1900, "inputs", 1), Err(Error::Missing(_))));
+        assert!(read(2022, "examples", 1).unwrap().contains("1000"));
+    }
+}
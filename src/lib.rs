@@ -4,91 +4,217 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::fmt::Display;
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+// lets solutions refer to `advent_of_code::` when they are compiled as part of the library.
+extern crate self as advent_of_code;
+
+pub mod aoc;
+pub mod bench;
+pub mod config;
+pub mod csv_log;
+pub mod days;
+pub mod error;
+pub mod example;
 pub mod helpers;
+pub mod history;
+pub mod input;
+pub mod puzzle;
+pub mod readme;
+pub mod report;
+mod solution;
+pub mod submit;
+pub mod template;
+pub mod verify;
+
+use config::Config;
+pub use solution::{run_part, Answer, Mode, Solution};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// The year this repository was set up for. Its files live directly in `src/`,
+/// files of any other year in `src/<year>/`.
+pub const DEFAULT_YEAR: i16 = 2022;
+
+/// Runs a solver, prints its result and returns it as a [`Solution`].
+/// Year and day are taken from the name of the binary, see [`parse_bin_name`].
+/// Pass `--bench` to the binary to run the solver repeatedly and print timing statistics,
+/// or `--submit <part>` to submit the answer to that part.
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
+        let (year, day) = option_env!("CARGO_BIN_NAME")
+            .and_then(advent_of_code::parse_bin_name)
+            .unwrap_or((advent_of_code::DEFAULT_YEAR, 0));
+        let solution = advent_of_code::run_part(
+            year,
+            day,
+            $part,
+            $solver,
+            $input,
+            advent_of_code::Mode::from_args(),
+        );
+        println!("{}", solution);
+        advent_of_code::submit::submit_if_requested(&solution);
+        solution
+    }};
+}
+
+/// Generates a test per part that checks the solvers against all examples of the day
+/// that have expected answers, see [`example::read_examples`].
+/// Call it inside the `tests` module of a solution: `advent_of_code::example_tests!(part_one, part_two);`
+/// Pass only `part_one` to skip part two, e.g. when its answer cannot be checked against a string.
+#[macro_export]
+macro_rules! example_tests {
+    ($part_one:ident) => {
+        mod examples {
+            use super::*;
+
+            #[test]
+            fn test_part_one() {
+                $crate::example::check(option_env!("CARGO_BIN_NAME"), 1, $part_one);
+            }
+        }
+    };
+    ($part_one:ident, $part_two:ident) => {
+        mod examples {
+            use super::*;
+
+            #[test]
+            fn test_part_one() {
+                $crate::example::check(option_env!("CARGO_BIN_NAME"), 1, $part_one);
+            }
+
+            #[test]
+            fn test_part_two() {
+                $crate::example::check(option_env!("CARGO_BIN_NAME"), 2, $part_two);
             }
         }
+    };
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+/// Parses `input` according to a `pattern` with a `{}` placeholder per value, returning the values
+/// as a tuple of the given types or a [`error::ParseError`] pointing at the part that did not match.
+/// `scan!(line, "{}-{},{}-{}", u32, u32, u32, u32)` parses `2-4,6-8` into `(2, 4, 6, 8)`.
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:literal, $($ty:ty),+ $(,)?) => {{
+        let input: &str = $input;
+        $crate::helpers::scan::fields(input, $pattern, [$(stringify!($ty)),+].len()).and_then(
+            |fields| {
+                let mut fields = fields.into_iter();
+                Ok(($($crate::helpers::scan::field::<$ty>(input, fields.next().unwrap(), stringify!($ty))?,)+))
+            },
+        )
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Solutions of the default year are named `NN`, solutions of other years `YYYY-NN`.
+pub fn parse_bin_name(name: &str) -> Option<(i16, u8)> {
+    match name.split_once('-') {
+        Some((year, day)) => Some((year.parse().ok()?, day.parse().ok()?)),
+        None => Some((DEFAULT_YEAR, name.parse().ok()?)),
+    }
+}
+
+pub fn bin_name(year: i16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("{:02}", day)
+    } else {
+        format!("{}-{:02}", year, day)
+    }
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Environment variable overriding the directory that inputs, examples etc. are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// The root of this repository, independent of the directory a binary is started from.
+pub fn project_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// `AOC_INPUT_DIR` takes precedence over `input_dir` in `.aoc/config`, the default is `src/`.
+/// Relative paths are resolved against the project directory.
+fn resolve_input_root(env_dir: Option<PathBuf>, config: &Config, project_dir: &Path) -> PathBuf {
+    env_dir
+        .or_else(|| config.input_dir.clone())
+        .map_or_else(|| project_dir.join("src"), |dir| project_dir.join(dir))
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+/// The directory holding the inputs, examples, answers and puzzles of the default year.
+pub fn input_root() -> Result<PathBuf, input::Error> {
+    let config = Config::load(project_dir()).map_err(input::Error::Config)?;
+    let env_dir = env::var_os(INPUT_DIR_VAR)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from);
+    Ok(resolve_input_root(env_dir, &config, project_dir()))
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+fn for_year(dir: PathBuf, year: i16) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        dir
+    } else {
+        dir.join(year.to_string())
+    }
+}
+
+/// The directory holding the inputs, examples etc. of a year.
+pub fn year_dir(year: i16) -> Result<PathBuf, input::Error> {
+    Ok(for_year(input_root()?, year))
+}
+
+/// The directory holding the solutions of a year.
+pub fn bin_dir(year: i16) -> PathBuf {
+    for_year(project_dir().join("src"), year).join("bin")
+}
+
+pub fn year_input_path(year: i16, folder: &str, day: u8) -> Result<PathBuf, input::Error> {
+    Ok(year_dir(year)?.join(folder).join(format!("{:02}.txt", day)))
+}
+
+pub fn input_path(folder: &str, day: u8) -> Result<PathBuf, input::Error> {
+    year_input_path(DEFAULT_YEAR, folder, day)
+}
+
+pub fn read_year_file(year: i16, folder: &str, day: u8) -> Result<String, input::Error> {
+    input::read(year, folder, day)
+}
+
+pub fn read_file(folder: &str, day: u8) -> Result<String, input::Error> {
+    read_year_file(DEFAULT_YEAR, folder, day)
+}
+
+/// Prints an error and exits the process with a failure code.
+pub fn exit_with_error(error: impl Display) -> ! {
+    eprintln!("{}", error);
+    process::exit(1)
+}
+
+/// Writes to a temporary file next to `path` and moves it into place,
+/// so readers never see a partially written file and concurrent writers do not collide.
+pub fn write_atomic(path: impl AsRef<Path>, contents: &str) -> io::Result<()> {
+    let path = path.as_ref();
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(dir)?;
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos();
+    let tmp_path = dir.join(format!(".{}.{}-{}.tmp", file_name, process::id(), nanos));
+
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp_path);
+    })
 }
 
 #[cfg(test)]
@@ -96,30 +222,60 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+    fn test_bin_name() {
+        assert_eq!(parse_bin_name("07"), Some((DEFAULT_YEAR, 7)));
+        assert_eq!(parse_bin_name("2021-12"), Some((2021, 12)));
+        assert_eq!(parse_bin_name("scaffold"), None);
+        assert_eq!(parse_bin_name("2021-x"), None);
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+        assert_eq!(bin_name(DEFAULT_YEAR, 7), "07");
+        assert_eq!(bin_name(2021, 12), "2021-12");
+    }
+
+    #[test]
+    fn test_resolve_input_root() {
+        let project = Path::new("/aoc");
+        let config = Config {
+            input_dir: Some(PathBuf::from("../inputs")),
+        };
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        assert_eq!(
+            resolve_input_root(None, &Config::default(), project),
+            project.join("src")
+        );
+        assert_eq!(
+            resolve_input_root(None, &config, project),
+            project.join("../inputs")
         );
+        assert_eq!(
+            resolve_input_root(Some(PathBuf::from("/data")), &config, project),
+            PathBuf::from("/data")
+        );
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+    #[test]
+    fn test_year_input_path() {
+        let src = input_root().unwrap();
+        assert_eq!(
+            year_input_path(DEFAULT_YEAR, "inputs", 1).unwrap(),
+            src.join("inputs").join("01.txt")
+        );
+        assert_eq!(
+            year_input_path(2021, "examples", 12).unwrap(),
+            src.join("2021").join("examples").join("12.txt")
         );
     }
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = env::temp_dir().join(format!("aoc_write_atomic_{}", process::id()));
+        let path = dir.join("01.txt");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
@@ -2,45 +2,155 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::days::{self, RunError};
+use advent_of_code::history::{self, History, Record};
+use advent_of_code::report::{self, Format};
+use advent_of_code::{
+    exit_with_error, Mode, Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DEFAULT_YEAR,
+};
+use std::time::Duration;
+
+/// Parts that got more than this many percent slower than their last recorded timing are flagged.
+const DEFAULT_THRESHOLD_PERCENT: f64 = 10_f64;
+
+struct Args {
+    year: i16,
+    /// Only run this day instead of all of them.
+    day: Option<u8>,
+    format: Format,
+    mode: Mode,
+    threshold: f64,
+    /// Neither compare against nor add to the benchmark history.
+    no_history: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        day: args.opt_value_from_str(["-d", "--day"])?,
+        format: args
+            .opt_value_from_str(["-f", "--format"])?
+            .unwrap_or_default(),
+        mode: if args.contains("--bench") {
+            Mode::Bench
+        } else {
+            Mode::Once
+        },
+        threshold: args
+            .opt_value_from_str(["-t", "--threshold"])?
+            .unwrap_or(DEFAULT_THRESHOLD_PERCENT),
+        no_history: args.contains("--no-history"),
+    })
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {}", e)),
+    };
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    let human = args.format == Format::Human;
+    // keep stdout parseable when emitting machine-readable output.
+    let report = |line: String| {
+        if human {
+            println!("{}", line)
+        } else {
+            eprintln!("{}", line)
+        }
+    };
+    let mut solutions = vec![];
 
+    let days = args.day.map_or(1..=25, |day| day..=day);
+    for day in days {
+        if human {
             println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
             println!("----------");
+        }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+        match days::run(args.year, day, args.mode) {
+            Ok(day_solutions) => {
+                if human {
+                    day_solutions
+                        .iter()
+                        .for_each(|solution| println!("{}", solution));
+                }
+                solutions.extend(day_solutions);
+            }
+            Err(RunError::NotSolved) => {
+                if human {
+                    println!("Not solved.");
                 }
-            );
-
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
             }
-        })
-        .sum();
+            Err(e) => report(format!("⚠️  Day {:02}: {}", day, e)),
+        }
+    }
+
+    let total: Duration = solutions.iter().map(|solution| solution.elapsed).sum();
+
+    match args.format {
+        Format::Human => println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        ),
+        Format::Json => println!("{}", report::to_json(&solutions, total)),
+        Format::Csv => print!("{}", report::to_csv(&solutions, total)),
+    }
+
+    if !args.no_history {
+        track_history(&solutions, args.threshold, report);
+    }
+}
+
+/// Compares this run against the benchmark history and records its timings.
+/// Regressions are passed to `report`.
+fn track_history(solutions: &[Solution], threshold: f64, report: impl Fn(String)) {
+    let mut history = match History::load(history::history_path()) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {}", e);
+            return;
+        }
+    };
+
+    let commit = history::current_commit();
+    let profile = history::current_profile();
+
+    let regressions = history::find_regressions(&history, solutions, &commit, profile, threshold);
+
+    if !regressions.is_empty() {
+        report(format!(
+            "{}Slower than {:.0}% threshold:{}",
+            ANSI_BOLD, threshold, ANSI_RESET
+        ));
+        regressions
+            .iter()
+            .for_each(|regression| report(format!("⚠️  {}", regression)));
+    }
+
+    solutions
+        .iter()
+        .filter(|solution| solution.is_solved())
+        .for_each(|solution| {
+            history.record(Record {
+                commit: commit.clone(),
+                profile: profile.to_string(),
+                mode: solution.mode(),
+                year: solution.year,
+                day: solution.day,
+                part: solution.part,
+                elapsed: solution.elapsed,
+            })
+        });
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if let Err(e) = history.save(history::history_path()) {
+        eprintln!("Failed to write benchmark history: {}", e);
+    }
 }
@@ -0,0 +1,324 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::aoc::{Client, Error};
+
+/// A tag or a piece of text of an HTML document.
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: String, attrs: &'a str },
+    Close(String),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let end = rest.find('>').unwrap_or(rest.len());
+                let tag = rest.get(1..end).unwrap_or_default();
+                if let Some(name) = tag.strip_prefix('/') {
+                    tokens.push(Token::Close(name.trim().to_lowercase()));
+                } else if !tag.is_empty() && !tag.starts_with('!') {
+                    let tag = tag.trim_end_matches('/');
+                    let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                    tokens.push(Token::Open {
+                        name: name.to_lowercase(),
+                        attrs,
+                    });
+                }
+                rest = rest.get(end + 1..).unwrap_or_default();
+            }
+            Some(start) => {
+                tokens.push(Token::Text(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                rest = "";
+            }
+        }
+    }
+
+    tokens
+}
+
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = start + attrs[start..].find('"')?;
+    Some(&attrs[start..end])
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Returns the contents of all `<article>` elements, i.e. the description of each part.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let content_start = start + rest[start..].find('>').map_or(0, |i| i + 1);
+        let end = rest[content_start..]
+            .find("</article>")
+            .map_or(rest.len(), |i| content_start + i);
+        articles.push(&rest[content_start..end]);
+        rest = &rest[end..];
+    }
+    articles
+}
+
+/// Returns the text of the first `<article>` element, or of the whole document if there is none,
+/// with runs of whitespace collapsed into single spaces.
+pub fn article_text(html: &str) -> String {
+    let article = articles(html).into_iter().next().unwrap_or(html);
+    let text: String = tokenize(article)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(decode_entities(text)),
+            _ => None,
+        })
+        .collect();
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Converts the puzzle description of a puzzle page to markdown.
+/// Only handles the small set of elements that puzzle descriptions are written with.
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    for article in articles(html) {
+        for token in tokenize(article) {
+            match token {
+                Token::Open { name, attrs } => match name.as_str() {
+                    "h2" => markdown.push_str("## "),
+                    "pre" => {
+                        in_pre = true;
+                        markdown.push_str("```\n");
+                    }
+                    "code" if !in_pre => markdown.push('`'),
+                    "em" if !in_pre => markdown.push_str("**"),
+                    "li" => markdown.push_str("- "),
+                    "br" => markdown.push('\n'),
+                    "a" => {
+                        links.push(attribute(attrs, "href").unwrap_or_default().to_string());
+                        markdown.push('[');
+                    }
+                    _ => (),
+                },
+                Token::Close(name) => match name.as_str() {
+                    "h2" | "p" | "ul" => markdown.push_str("\n\n"),
+                    "pre" => {
+                        in_pre = false;
+                        if !markdown.ends_with('\n') {
+                            markdown.push('\n');
+                        }
+                        markdown.push_str("```\n\n");
+                    }
+                    "code" if !in_pre => markdown.push('`'),
+                    "em" if !in_pre => markdown.push_str("**"),
+                    "li" => markdown.push('\n'),
+                    "a" => markdown.push_str(&format!("]({})", links.pop().unwrap_or_default())),
+                    _ => (),
+                },
+                // line breaks between block elements carry no meaning outside of `<pre>`.
+                Token::Text(text) if !in_pre && text.trim().is_empty() && text.contains('\n') => (),
+                Token::Text(text) => markdown.push_str(&decode_entities(text)),
+            }
+        }
+    }
+
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    format!("{}\n", markdown.trim())
+}
+
+/// Returns the contents of the first `<pre><code>` block, which usually is the example input.
+pub fn extract_example(html: &str) -> Option<String> {
+    let article = articles(html).into_iter().next()?;
+    let tokens = tokenize(article);
+
+    let start = tokens.windows(2).position(|window| {
+        matches!(&window[0], Token::Open { name, .. } if name == "pre")
+            && matches!(&window[1], Token::Open { name, .. } if name == "code")
+    })? + 2;
+
+    let mut example = String::new();
+    for token in &tokens[start..] {
+        match token {
+            Token::Close(name) if name == "code" => break,
+            Token::Text(text) => example.push_str(&decode_entities(text)),
+            _ => (),
+        }
+    }
+    Some(example)
+}
+
+/// The title of a puzzle, read from the `--- Day 1: Calorie Counting ---` heading of its description.
+/// Works on both the page and its markdown.
+pub fn title(description: &str) -> Option<String> {
+    let heading = description.split("--- Day ").nth(1)?;
+    let (_, rest) = heading.split_once(": ")?;
+    let (title, _) = rest.split_once(" ---")?;
+    Some(decode_entities(title))
+}
+
+/// Fetches the puzzle page and writes its description to `puzzles/NN.md` in the directory of its year.
+/// Returns the raw page so callers can extract more from it.
+pub fn fetch_and_store(client: &Client, year: i16, day: u8) -> Result<String, Error> {
+    let html = client.puzzle(year, day)?;
+    store(&html, year, day)?;
+    Ok(html)
+}
+
+/// Writes the description of a puzzle page to `puzzles/NN.md` in the directory of its year.
+pub fn store(html: &str, year: i16, day: u8) -> Result<(), Error> {
+    crate::write_atomic(puzzle_path(year, day)?, &to_markdown(html))?;
+    Ok(())
+}
+
+pub fn puzzle_path(year: i16, day: u8) -> io::Result<PathBuf> {
+    Ok(crate::year_input_path(year, "puzzles", day)?.with_extension("md"))
+}
+
+/// Writes the example of a puzzle page to `examples/NN.txt`, unless that file already has content.
+/// Returns whether the example was written.
+pub fn store_example(html: &str, year: i16, day: u8) -> Result<bool, Error> {
+    let path = crate::year_input_path(year, "examples", day).map_err(io::Error::from)?;
+    let has_content = fs::read_to_string(&path).is_ok_and(|contents| !contents.trim().is_empty());
+
+    match extract_example(html) {
+        Some(example) if !has_content => {
+            crate::write_atomic(&path, &example)?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2022</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The jungle must be too overgrown and difficult to navigate in <a href="/2022/day/1">vehicles</a>.</p>
+<p>For example:</p>
+<pre><code>1000
+<em>2000</em>
+3000 &lt; 4000
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code>6000</code> Calories.</li>
+<li>In the example above, this is <em><code>24000</code></em>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>70369</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>By the time you calculate the answer...</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE),
+            r#"## --- Day 1: Calorie Counting ---
+
+The jungle must be too overgrown and difficult to navigate in [vehicles](/2022/day/1).
+
+For example:
+
+```
+1000
+2000
+3000 < 4000
+```
+
+- The first Elf is carrying `6000` Calories.
+- In the example above, this is **`24000`**.
+
+## --- Part Two ---
+
+By the time you calculate the answer...
+"#
+        );
+    }
+
+    #[test]
+    fn test_tokenize_unclosed_tag() {
+        assert_eq!(tokenize("a<"), vec![Token::Text("a")]);
+        assert_eq!(
+            tokenize("<p>é<"),
+            vec![
+                Token::Open {
+                    name: "p".to_string(),
+                    attrs: ""
+                },
+                Token::Text("é")
+            ]
+        );
+        assert_eq!(
+            tokenize("<é"),
+            vec![Token::Open {
+                name: "é".to_string(),
+                attrs: ""
+            }]
+        );
+    }
+
+    #[test]
+    fn test_article_text() {
+        assert_eq!(
+            article_text(
+                "<main><article><p>That&#39;s   the\n<em>right</em> answer!</p></article></main>"
+            ),
+            "That's the right answer!"
+        );
+        assert_eq!(article_text("<p>no  article</p>"), "no article");
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(title(PAGE), Some("Calorie Counting".to_string()));
+        assert_eq!(
+            title(&to_markdown(PAGE)),
+            Some("Calorie Counting".to_string())
+        );
+        assert_eq!(title("## --- Part Two ---"), None);
+    }
+
+    #[test]
+    fn test_extract_example() {
+        assert_eq!(
+            extract_example(PAGE),
+            Some("1000\n2000\n3000 < 4000\n".to_string())
+        );
+        assert_eq!(
+            extract_example("<article><p>no example</p></article>"),
+            None
+        );
+        assert_eq!(extract_example("<p>no article</p>"), None);
+    }
+}
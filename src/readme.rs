@@ -0,0 +1,170 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::Duration;
+
+/// Marks the end of the results table of the default year in the readme.
+pub const TABLE_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Marks the end of the results table of `year`. Other years get their own marker
+/// so updating one table never touches another.
+pub fn table_marker(year: i16) -> String {
+    if year == crate::DEFAULT_YEAR {
+        TABLE_MARKER.to_string()
+    } else {
+        format!("<!--- advent_readme_stars table {} --->", year)
+    }
+}
+
+/// The verified state of a single day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    /// Whether the answer to a part matches the recorded answer.
+    pub stars: [bool; 2],
+    /// Only rendered if all rows have timings.
+    pub timings: Option<[Duration; 2]>,
+}
+
+pub fn render_table(year: i16, rows: &[Row]) -> String {
+    let with_timings = !rows.is_empty() && rows.iter().all(|row| row.timings.is_some());
+
+    let mut table = format!("## {} Results\n\n", year);
+    if with_timings {
+        table.push_str("| Day | Part 1 | Part 2 | Time (Part 1) | Time (Part 2) |\n");
+        table.push_str("| :---: | :---: | :---: | :---: | :---: |\n");
+    } else {
+        table.push_str("| Day | Part 1 | Part 2 |\n");
+        table.push_str("| :---: | :---: | :---: |\n");
+    }
+
+    for row in rows {
+        let star = |solved: bool| if solved { "⭐" } else { "" };
+        table.push_str(&format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |",
+            row.day,
+            year,
+            row.day,
+            star(row.stars[0]),
+            star(row.stars[1])
+        ));
+        if let (true, Some([one, two])) = (with_timings, row.timings) {
+            table.push_str(&format!(" {:.2?} | {:.2?} |", one, two));
+        }
+        table.push('\n');
+    }
+
+    table
+}
+
+/// Replaces the results table of `year` in front of its [`table_marker`], starting at its
+/// `## <year> Results` heading. If there is no heading, the table is inserted right before the marker.
+/// The table of a year other than the default one is added after the default table if it has no marker yet.
+/// Returns `None` if the readme has no marker for the default year.
+pub fn update(readme: &str, year: i16, table: &str) -> Option<String> {
+    let marker = table_marker(year);
+    let Some(end) = readme.find(&marker) else {
+        let end = readme.find(TABLE_MARKER)? + TABLE_MARKER.len();
+        return Some(format!(
+            "{}\n\n{}{}{}",
+            &readme[..end],
+            table,
+            marker,
+            &readme[end..]
+        ));
+    };
+
+    let heading = format!("## {} Results", year);
+    let before = &readme[..end];
+    let start = before
+        .match_indices(&heading)
+        .map(|(i, _)| i)
+        .filter(|i| *i == 0 || before[..*i].ends_with('\n'))
+        .last()
+        .unwrap_or(end);
+
+    Some(format!("{}{}{}", &readme[..start], table, &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(timings: bool) -> Vec<Row> {
+        vec![
+            Row {
+                day: 1,
+                stars: [true, true],
+                timings: timings.then_some([Duration::from_micros(12), Duration::from_millis(3)]),
+            },
+            Row {
+                day: 2,
+                stars: [true, false],
+                timings: timings.then_some([Duration::from_nanos(800), Duration::ZERO]),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render_table() {
+        assert_eq!(
+            render_table(2022, &rows(false)),
+            concat!(
+                "## 2022 Results\n\n",
+                "| Day | Part 1 | Part 2 |\n",
+                "| :---: | :---: | :---: |\n",
+                "| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n",
+                "| [Day 2](https://adventofcode.com/2022/day/2) | ⭐ |  |\n",
+            )
+        );
+        assert_eq!(
+            render_table(2022, &rows(true)),
+            concat!(
+                "## 2022 Results\n\n",
+                "| Day | Part 1 | Part 2 | Time (Part 1) | Time (Part 2) |\n",
+                "| :---: | :---: | :---: | :---: | :---: |\n",
+                "| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ | 12.00µs | 3.00ms |\n",
+                "| [Day 2](https://adventofcode.com/2022/day/2) | ⭐ |  | 800.00ns | 0.00ns |\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_update() {
+        let readme = "# AoC\n\n## 2022 Results\n\n| old |\n<!--- advent_readme_stars table --->\n\n## Usage\n";
+        assert_eq!(
+            update(readme, 2022, "## 2022 Results\n\n| new |\n"),
+            Some("# AoC\n\n## 2022 Results\n\n| new |\n<!--- advent_readme_stars table --->\n\n## Usage\n".to_string())
+        );
+
+        let readme = "# AoC\n\n<!--- advent_readme_stars table --->\n";
+        assert_eq!(
+            update(readme, 2022, "## 2022 Results\n\n| new |\n"),
+            Some(
+                "# AoC\n\n## 2022 Results\n\n| new |\n<!--- advent_readme_stars table --->\n"
+                    .to_string()
+            )
+        );
+
+        assert_eq!(update("# AoC\n", 2022, "| new |\n"), None);
+    }
+
+    #[test]
+    fn test_update_other_year() {
+        let readme =
+            "## 2022 Results\n\n| 2022 |\n<!--- advent_readme_stars table --->\n\n## Usage\n";
+        let added = update(readme, 2021, "## 2021 Results\n\n| old |\n").unwrap();
+        assert_eq!(
+            added,
+            "## 2022 Results\n\n| 2022 |\n<!--- advent_readme_stars table --->\n\n## 2021 Results\n\n| old |\n<!--- advent_readme_stars table 2021 --->\n\n## Usage\n"
+        );
+
+        let updated = update(&added, 2021, "## 2021 Results\n\n| new |\n").unwrap();
+        assert_eq!(updated, added.replace("| old |", "| new |"));
+        assert_eq!(
+            update(&updated, 2022, "## 2022 Results\n\n| new 2022 |\n").unwrap(),
+            updated.replace("| 2022 |", "| new 2022 |")
+        );
+    }
+}
@@ -0,0 +1,240 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::bench::Stats;
+use crate::Solution;
+
+/// Output format of `cargo all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: human, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_csv(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn stats_to_json(stats: &Stats) -> String {
+    format!(
+        "{{\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+        stats.iterations,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.stddev.as_nanos()
+    )
+}
+
+/// Timings are reported in nanoseconds so runs can be compared without rounding.
+pub fn to_json(solutions: &[Solution], total: Duration) -> String {
+    let entries: Vec<String> = solutions
+        .iter()
+        .map(|solution| {
+            format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"solved\":{},\"answer\":{},\"elapsed_ns\":{},\"bench\":{}}}",
+                solution.year,
+                solution.day,
+                solution.part,
+                solution.is_solved(),
+                solution
+                    .answer
+                    .as_ref()
+                    .map_or("null".to_string(), |answer| format!(
+                        "\"{}\"",
+                        escape_json(answer)
+                    )),
+                solution.elapsed.as_nanos(),
+                solution
+                    .bench
+                    .as_ref()
+                    .map_or("null".to_string(), stats_to_json)
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"solutions\":[{}],\"total_ns\":{}}}",
+        entries.join(","),
+        total.as_nanos()
+    )
+}
+
+/// The last row carries the total with `total` in place of the year.
+/// Benchmark columns are left empty unless the run used `--bench`.
+pub fn to_csv(solutions: &[Solution], total: Duration) -> String {
+    let mut csv = String::from(
+        "year,day,part,solved,answer,elapsed_ns,iterations,min_ns,median_ns,mean_ns,stddev_ns\n",
+    );
+    for solution in solutions {
+        let bench = solution.bench.as_ref().map_or(",,,,".to_string(), |stats| {
+            format!(
+                "{},{},{},{},{}",
+                stats.iterations,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )
+        });
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            solution.year,
+            solution.day,
+            solution.part,
+            solution.is_solved(),
+            escape_csv(solution.answer.as_deref().unwrap_or_default()),
+            solution.elapsed.as_nanos(),
+            bench
+        ));
+    }
+    csv.push_str(&format!("total,,,,,{},,,,,\n", total.as_nanos()));
+    csv
+}
+
+/// Splits a line of [`to_csv`] output into its fields, undoing the escaping of answers.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            ch => fields.last_mut().unwrap().push(ch),
+        }
+    }
+    fields
+}
+
+/// Reads `(day, part, answer)` of every solution back from [`to_csv`] output.
+/// The answer is `None` if the part is not solved.
+pub fn answers_from_csv(csv: &str) -> Vec<(u8, u8, Option<String>)> {
+    csv.lines()
+        .skip(1)
+        .map(split_csv_line)
+        .filter_map(|fields| {
+            let day = fields.get(1)?.parse().ok()?;
+            let part = fields.get(2)?.parse().ok()?;
+            let answer = (fields.get(3)? == "true").then(|| fields[4].clone());
+            Some((day, part, answer))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solutions() -> Vec<Solution> {
+        vec![
+            Solution::for_test(1, 1, Some("24000"), Duration::from_nanos(1500)),
+            Solution::for_test(1, 2, None, Duration::from_nanos(20)),
+            Solution {
+                bench: Some(Stats {
+                    iterations: 10,
+                    min: Duration::from_nanos(2),
+                    median: Duration::from_nanos(3),
+                    mean: Duration::from_nanos(4),
+                    stddev: Duration::from_nanos(1),
+                }),
+                ..Solution::for_test(5, 1, Some("C\"M,Z"), Duration::from_nanos(3))
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("human".parse(), Ok(Format::Human));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&solutions(), Duration::from_nanos(1523)),
+            concat!(
+                "{\"solutions\":[",
+                "{\"year\":2022,\"day\":1,\"part\":1,\"solved\":true,\"answer\":\"24000\",\"elapsed_ns\":1500,\"bench\":null},",
+                "{\"year\":2022,\"day\":1,\"part\":2,\"solved\":false,\"answer\":null,\"elapsed_ns\":20,\"bench\":null},",
+                "{\"year\":2022,\"day\":5,\"part\":1,\"solved\":true,\"answer\":\"C\\\"M,Z\",\"elapsed_ns\":3,",
+                "\"bench\":{\"iterations\":10,\"min_ns\":2,\"median_ns\":3,\"mean_ns\":4,\"stddev_ns\":1}}",
+                "],\"total_ns\":1523}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&solutions(), Duration::from_nanos(1523)),
+            concat!(
+                "year,day,part,solved,answer,elapsed_ns,iterations,min_ns,median_ns,mean_ns,stddev_ns\n",
+                "2022,1,1,true,24000,1500,,,,,\n",
+                "2022,1,2,false,,20,,,,,\n",
+                "2022,5,1,true,\"C\"\"M,Z\",3,10,2,3,4,1\n",
+                "total,,,,,1523,,,,,\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_answers_from_csv() {
+        assert_eq!(
+            answers_from_csv(&to_csv(&solutions(), Duration::from_nanos(1523))),
+            vec![
+                (1, 1, Some("24000".to_string())),
+                (1, 2, None),
+                (5, 1, Some("C\"M,Z".to_string())),
+            ]
+        );
+    }
+}
@@ -0,0 +1,251 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::bench::{self, Stats};
+use crate::error;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// How a solver is run: once, or repeatedly to collect timing statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    #[default]
+    Once,
+    Bench,
+}
+
+impl Mode {
+    /// `Bench` if the binary was called with `--bench`.
+    pub fn from_args() -> Self {
+        if env::args().any(|arg| arg == "--bench") {
+            Mode::Bench
+        } else {
+            Mode::Once
+        }
+    }
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Once => write!(f, "once"),
+            Mode::Bench => write!(f, "bench"),
+        }
+    }
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "once" => Ok(Mode::Once),
+            "bench" => Ok(Mode::Bench),
+            _ => Err(format!("unknown mode {:?}", s)),
+        }
+    }
+}
+
+/// What a solver can return: a plain answer, `Option<T>`, where `None` means the part is
+/// not solved yet, or `Result` of either to report why a part could not be solved.
+pub trait Answer {
+    /// The rendered answer, `Ok(None)` if the part is not solved.
+    fn into_answer(self) -> Result<Option<String>, String>;
+}
+
+macro_rules! impl_answer {
+    ($($ty:ty),+) => {$(
+        impl Answer for $ty {
+            fn into_answer(self) -> Result<Option<String>, String> {
+                Ok(Some(self.to_string()))
+            }
+        }
+    )+};
+}
+
+impl_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str);
+
+impl<T: Display> Answer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<T: Answer, E: Into<Box<dyn std::error::Error>>> Answer for Result<T, E> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        self.map_err(|e| error::chain(e.into().as_ref()))?
+            .into_answer()
+    }
+}
+
+/// The outcome of running one part of a day's solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the solver returned `None` or an error, i.e. the part is not solved yet.
+    pub answer: Option<String>,
+    /// The error returned by the solver, including its sources.
+    pub error: Option<String>,
+    /// The median of all runs when benchmarked.
+    pub elapsed: Duration,
+    /// Only present when run in [`Mode::Bench`].
+    pub bench: Option<Stats>,
+}
+
+impl Solution {
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    /// The mode the solution was run in, inferred from whether it has benchmark statistics.
+    pub fn mode(&self) -> Mode {
+        if self.bench.is_some() {
+            Mode::Bench
+        } else {
+            Mode::Once
+        }
+    }
+
+    /// A solution of a 2022 puzzle for tests. `None` means the part is not solved.
+    #[cfg(test)]
+    pub(crate) fn for_test(day: u8, part: u8, answer: Option<&str>, elapsed: Duration) -> Self {
+        Solution {
+            year: 2022,
+            day,
+            part,
+            answer: answer.map(str::to_string),
+            error: None,
+            elapsed,
+            bench: None,
+        }
+    }
+}
+
+/// Renders the solution the way `cargo solve` prints it to the console.
+impl Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET)?;
+        match &self.answer {
+            Some(answer) => match &self.bench {
+                Some(stats) => write!(f, "{} {}({}){}", answer, ANSI_ITALIC, stats, ANSI_RESET),
+                None => write!(
+                    f,
+                    "{} {}(elapsed: {:.2?}){}",
+                    answer, ANSI_ITALIC, self.elapsed, ANSI_RESET
+                ),
+            },
+            None => match &self.error {
+                Some(error) => write!(f, "failed: {}", error),
+                None => write!(f, "not solved."),
+            },
+        }
+    }
+}
+
+pub fn run_part<A: Answer>(
+    year: i16,
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> A,
+    input: &str,
+    mode: Mode,
+) -> Solution {
+    let (result, elapsed, bench) = match mode {
+        Mode::Once => {
+            let timer = Instant::now();
+            let result = func(input);
+            (result, timer.elapsed(), None)
+        }
+        Mode::Bench => {
+            let (result, stats) = bench::bench(func, input);
+            (result, stats.median, Some(stats))
+        }
+    };
+
+    let (answer, error) = match result.into_answer() {
+        Ok(answer) => (answer, None),
+        Err(error) => (None, Some(error)),
+    };
+
+    Solution {
+        year,
+        day,
+        part,
+        answer,
+        error,
+        elapsed,
+        bench,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_part() {
+        let solution = run_part(
+            2022,
+            3,
+            2,
+            |input| input.parse::<u32>().ok(),
+            "42",
+            Mode::Once,
+        );
+        assert_eq!(solution.year, 2022);
+        assert_eq!(solution.day, 3);
+        assert_eq!(solution.part, 2);
+        assert_eq!(solution.answer, Some("42".to_string()));
+        assert!(solution.is_solved());
+
+        let solution = run_part(2022, 3, 1, |_| None::<u32>, "42", Mode::Once);
+        assert_eq!(solution.answer, None);
+        assert!(!solution.is_solved());
+
+        let solution = run_part(2022, 3, 1, |input| input.parse::<u32>(), "4x", Mode::Once);
+        assert_eq!(solution.answer, None);
+        assert_eq!(
+            solution.error,
+            Some("invalid digit found in string".to_string())
+        );
+
+        let solution = run_part(
+            2022,
+            3,
+            1,
+            |input| input.parse::<u32>().map(|_| None::<u32>),
+            "42",
+            Mode::Once,
+        );
+        assert_eq!(solution.answer, None);
+        assert_eq!(solution.error, None);
+    }
+
+    #[test]
+    fn test_display() {
+        let solution = Solution::for_test(1, 1, None, Duration::ZERO);
+        assert_eq!(
+            solution.to_string(),
+            format!("🎄 {}Part 1{} 🎄\nnot solved.", ANSI_BOLD, ANSI_RESET)
+        );
+
+        let solution = Solution {
+            error: Some("line 1, column 1: expected digit".to_string()),
+            ..solution
+        };
+        assert_eq!(
+            solution.to_string(),
+            format!(
+                "🎄 {}Part 1{} 🎄\nfailed: line 1, column 1: expected digit",
+                ANSI_BOLD, ANSI_RESET
+            )
+        );
+    }
+}
@@ -0,0 +1,400 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::aoc::{self, Client, Outcome};
+use crate::csv_log::{self, Row};
+use crate::{puzzle, verify, Solution};
+
+/// Location of the verdict log, relative to the project directory.
+pub const SUBMISSIONS_PATH: &str = ".aoc/submissions.csv";
+
+pub fn submissions_path() -> PathBuf {
+    crate::project_dir().join(SUBMISSIONS_PATH)
+}
+
+/// A submitted answer and the server's verdict on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Row for Submission {
+    const HEADER: &'static str = "year,day,part,verdict,answer";
+
+    fn parse(line: &str) -> Option<Self> {
+        // the answer comes last so it may contain commas.
+        let mut fields = line.splitn(5, ',');
+        Some(Submission {
+            year: fields.next()?.parse().ok()?,
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            outcome: parse_outcome(fields.next()?)?,
+            answer: fields.next()?.to_string(),
+        })
+    }
+}
+
+impl Submission {
+    fn is_for(&self, year: i16, day: u8, part: u8) -> bool {
+        self.year == year && self.day == day && self.part == part
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.year,
+            self.day,
+            self.part,
+            outcome_to_str(&self.outcome),
+            self.answer
+        )
+    }
+}
+
+fn outcome_to_str(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Correct => "correct".to_string(),
+        Outcome::TooHigh => "too_high".to_string(),
+        Outcome::TooLow => "too_low".to_string(),
+        Outcome::Incorrect => "incorrect".to_string(),
+        Outcome::Wait { seconds } => format!("wait_{}", seconds),
+        Outcome::WrongLevel => "wrong_level".to_string(),
+        Outcome::Unknown(_) => "unknown".to_string(),
+    }
+}
+
+fn parse_outcome(s: &str) -> Option<Outcome> {
+    match s {
+        "correct" => Some(Outcome::Correct),
+        "too_high" => Some(Outcome::TooHigh),
+        "too_low" => Some(Outcome::TooLow),
+        "incorrect" => Some(Outcome::Incorrect),
+        "wrong_level" => Some(Outcome::WrongLevel),
+        "unknown" => Some(Outcome::Unknown(String::new())),
+        _ => Some(Outcome::Wait {
+            seconds: s.strip_prefix("wait_")?.parse().ok()?,
+        }),
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved {
+        answer: String,
+    },
+    KnownWrong {
+        outcome: Outcome,
+    },
+    /// The answer is at least as high as one that was too high.
+    AboveTooHigh {
+        bound: String,
+    },
+    /// The answer is at most as low as one that was too low.
+    BelowTooLow {
+        bound: String,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "this part is already solved with answer {}", answer)
+            }
+            Refusal::KnownWrong { outcome } => {
+                write!(f, "this answer was already submitted and is {}", outcome)
+            }
+            Refusal::AboveTooHigh { bound } => {
+                write!(f, "{} was already too high", bound)
+            }
+            Refusal::BelowTooLow { bound } => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Refused(Refusal),
+    Client(aoc::Error),
+    Io(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Refused(refusal) => write!(f, "refusing to submit: {}", refusal),
+            Error::Client(e) => write!(f, "{}", e),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<aoc::Error> for Error {
+    fn from(e: aoc::Error) -> Self {
+        Error::Client(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Verdicts of previous submissions, oldest first.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    /// A missing file yields an empty log. Malformed lines are skipped.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Submissions {
+            submissions: csv_log::load(path)?,
+        })
+    }
+
+    #[cfg(test)]
+    fn parse(contents: &str) -> Self {
+        Submissions {
+            submissions: csv_log::parse(contents),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        csv_log::save(path, &self.submissions)
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// Checks an answer against earlier verdicts. Returns why it should not be submitted, if anything.
+    pub fn check(&self, year: i16, day: u8, part: u8, answer: &str) -> Option<Refusal> {
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.is_for(year, day, part));
+
+        let number = answer.parse::<i64>().ok();
+
+        for submission in previous {
+            match &submission.outcome {
+                Outcome::Correct => {
+                    return Some(Refusal::AlreadySolved {
+                        answer: submission.answer.clone(),
+                    })
+                }
+                outcome if outcome.is_wrong() && submission.answer == answer => {
+                    return Some(Refusal::KnownWrong {
+                        outcome: outcome.clone(),
+                    })
+                }
+                _ => (),
+            }
+
+            let bound = submission.answer.parse::<i64>().ok();
+            match (&submission.outcome, number, bound) {
+                (Outcome::TooHigh, Some(number), Some(bound)) if number >= bound => {
+                    return Some(Refusal::AboveTooHigh {
+                        bound: submission.answer.clone(),
+                    })
+                }
+                (Outcome::TooLow, Some(number), Some(bound)) if number <= bound => {
+                    return Some(Refusal::BelowTooLow {
+                        bound: submission.answer.clone(),
+                    })
+                }
+                _ => (),
+            }
+        }
+
+        None
+    }
+}
+
+impl Display for Submissions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", csv_log::render(&self.submissions))
+    }
+}
+
+/// Submits an answer unless earlier verdicts rule it out, and records the verdict in `submissions`.
+pub fn submit(
+    client: &Client,
+    submissions: &mut Submissions,
+    year: i16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, Error> {
+    if let Some(refusal) = submissions.check(year, day, part, answer) {
+        return Err(Error::Refused(refusal));
+    }
+
+    let outcome = client.submit(year, day, part, answer)?;
+    submissions.record(Submission {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: outcome.clone(),
+    });
+    Ok(outcome)
+}
+
+/// Submits an answer with a client configured from the environment, persists the verdict
+/// in `.aoc/submissions.csv` and stores correct answers in the answers file of the day.
+/// A stored puzzle description is refreshed once part one is solved.
+pub fn submit_and_record(year: i16, day: u8, part: u8, answer: &str) -> Result<Outcome, Error> {
+    let client = Client::from_env()?;
+    let mut submissions = Submissions::load(submissions_path())?;
+
+    let result = submit(&client, &mut submissions, year, day, part, answer);
+    submissions.save(submissions_path())?;
+
+    if let Ok(Outcome::Correct) = result {
+        verify::write_answer(year, day, part, answer)?;
+
+        // the description of part two becomes available once part one is solved.
+        if part == 1 && puzzle::puzzle_path(year, day).is_ok_and(|path| path.exists()) {
+            if let Err(e) = puzzle::fetch_and_store(&client, year, day) {
+                eprintln!("Failed to refresh puzzle description: {}", e);
+            }
+        }
+    }
+    result
+}
+
+/// Submits `solution` if the binary was called with `--submit <part>` for its part.
+pub fn submit_if_requested(solution: &Solution) {
+    let mut args = pico_args::Arguments::from_vec(env::args_os().skip(1).collect());
+    let part: Option<u8> = args.opt_value_from_str("--submit").ok().flatten();
+    if part != Some(solution.part) {
+        return;
+    }
+
+    match &solution.answer {
+        Some(answer) => match submit_and_record(solution.year, solution.day, solution.part, answer)
+        {
+            Ok(outcome) => println!("Submitted {}: {}", answer, outcome),
+            Err(e) => eprintln!("Failed to submit {}: {}", answer, e),
+        },
+        None => eprintln!("Part {} is not solved, nothing to submit.", solution.part),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::tests::serve_once;
+
+    fn submission(part: u8, answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            year: 2022,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let mut submissions = Submissions::default();
+        submissions.record(submission(1, "12", Outcome::TooLow));
+        submissions.record(submission(1, "1,2", Outcome::Wait { seconds: 30 }));
+        submissions.record(submission(1, "15", Outcome::Correct));
+
+        let serialized = submissions.to_string();
+        assert_eq!(
+            serialized,
+            "year,day,part,verdict,answer\n2022,1,1,too_low,12\n2022,1,1,wait_30,1,2\n2022,1,1,correct,15\n"
+        );
+        assert_eq!(Submissions::parse(&serialized), submissions);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut submissions = Submissions::default();
+        submissions.record(submission(1, "10", Outcome::TooLow));
+        submissions.record(submission(1, "50", Outcome::TooHigh));
+        submissions.record(submission(1, "abc", Outcome::Incorrect));
+        submissions.record(submission(2, "7", Outcome::Correct));
+
+        assert_eq!(submissions.check(2022, 1, 1, "20"), None);
+        assert_eq!(submissions.check(2023, 1, 1, "10"), None);
+        assert_eq!(
+            submissions.check(2022, 1, 1, "abc"),
+            Some(Refusal::KnownWrong {
+                outcome: Outcome::Incorrect
+            })
+        );
+        assert_eq!(
+            submissions.check(2022, 1, 1, "9"),
+            Some(Refusal::BelowTooLow {
+                bound: "10".to_string()
+            })
+        );
+        assert_eq!(
+            submissions.check(2022, 1, 1, "50"),
+            Some(Refusal::KnownWrong {
+                outcome: Outcome::TooHigh
+            })
+        );
+        assert_eq!(
+            submissions.check(2022, 1, 1, "51"),
+            Some(Refusal::AboveTooHigh {
+                bound: "50".to_string()
+            })
+        );
+        assert_eq!(
+            submissions.check(2022, 1, 2, "8"),
+            Some(Refusal::AlreadySolved {
+                answer: "7".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_submit_records_verdict() {
+        let (base_url, handle) = serve_once(
+            200,
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        );
+        let client = Client::new(&base_url, "abc");
+        let mut submissions = Submissions::default();
+
+        let outcome = submit(&client, &mut submissions, 2022, 1, 1, "100").unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        handle.join().unwrap();
+
+        assert_eq!(
+            submissions.submissions,
+            vec![submission(1, "100", Outcome::TooHigh)]
+        );
+
+        // the server is gone, so this only passes if the answer is refused locally.
+        assert!(matches!(
+            submit(&client, &mut submissions, 2022, 1, 1, "100"),
+            Err(Error::Refused(Refusal::KnownWrong { .. }))
+        ));
+    }
+}
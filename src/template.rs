@@ -0,0 +1,181 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Directory of the module templates used by `cargo scaffold`, relative to the project directory.
+pub const TEMPLATES_DIR: &str = ".aoc/templates";
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Used as the default template unless `.aoc/templates/default.rs` exists.
+pub const BUILTIN_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    None
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", {{DAY}})
+        .unwrap_or_else(|e| advent_of_code::exit_with_error(e));
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::example_tests!(part_one, part_two);
+}
+"###;
+
+#[derive(Debug)]
+pub enum Error {
+    NotFound {
+        name: String,
+        available: Vec<String>,
+    },
+    Io(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound { name, available } => write!(
+                f,
+                "no template \"{}\" in {}, available: {}",
+                name,
+                TEMPLATES_DIR,
+                available.join(", ")
+            ),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+fn templates_dir() -> PathBuf {
+    crate::project_dir().join(TEMPLATES_DIR)
+}
+
+/// Names of all templates, i.e. the file stems of `.aoc/templates/*.rs` and the default template.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(templates_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    if !names.iter().any(|name| name == DEFAULT_TEMPLATE) {
+        names.push(DEFAULT_TEMPLATE.to_string());
+    }
+    names.sort();
+    names
+}
+
+/// Reads `.aoc/templates/<name>.rs`, falling back to [`BUILTIN_TEMPLATE`] for the default template.
+pub fn load(name: &str) -> Result<String, Error> {
+    match fs::read_to_string(templates_dir().join(format!("{}.rs", name))) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            if name == DEFAULT_TEMPLATE {
+                Ok(BUILTIN_TEMPLATE.to_string())
+            } else {
+                Err(Error::NotFound {
+                    name: name.to_string(),
+                    available: available(),
+                })
+            }
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Values substituted into a template.
+pub struct Placeholders {
+    pub year: i16,
+    pub day: u8,
+    /// The puzzle title, empty if it is not known yet.
+    pub title: String,
+}
+
+/// Replaces `{{YEAR}}`, `{{DAY}}`, `{{DAY_PADDED}}` and `{{TITLE}}` in a template.
+/// Solutions of other years read their files from the directory of that year,
+/// so `read_file` calls are turned into `read_year_file` calls for them.
+pub fn render(template: &str, placeholders: &Placeholders) -> String {
+    let module = template
+        .replace("{{YEAR}}", &placeholders.year.to_string())
+        .replace("{{DAY_PADDED}}", &format!("{:02}", placeholders.day))
+        .replace("{{DAY}}", &placeholders.day.to_string())
+        .replace("{{TITLE}}", &placeholders.title);
+
+    if placeholders.year == crate::DEFAULT_YEAR {
+        module
+    } else {
+        module.replace(
+            "advent_of_code::read_file(",
+            &format!("advent_of_code::read_year_file({}, ", placeholders.year),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let template = "// {{YEAR}} day {{DAY_PADDED}}: {{TITLE}}\nlet input = advent_of_code::read_file(\"inputs\", {{DAY}});";
+        let placeholders = Placeholders {
+            year: crate::DEFAULT_YEAR,
+            day: 7,
+            title: "No Space Left On Device".to_string(),
+        };
+        assert_eq!(
+            render(template, &placeholders),
+            format!(
+                "// {} day 07: No Space Left On Device\nlet input = advent_of_code::read_file(\"inputs\", 7);",
+                crate::DEFAULT_YEAR
+            )
+        );
+
+        let placeholders = Placeholders {
+            year: 2021,
+            day: 12,
+            title: String::new(),
+        };
+        assert_eq!(
+            render(template, &placeholders),
+            "// 2021 day 12: \nlet input = advent_of_code::read_year_file(2021, \"inputs\", 12);"
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        assert!(load(DEFAULT_TEMPLATE).unwrap().contains("{{DAY}}"));
+        assert!(matches!(
+            load("does-not-exist"),
+            Err(Error::NotFound { .. })
+        ));
+        assert!(available().contains(&DEFAULT_TEMPLATE.to_string()));
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+
+use crate::Solution;
+
+/// Expected answers of a day, read from `answers/NN.txt` in the directory of its year.
+/// The first line holds the answer to part one, the second line the answer to part two.
+/// Leave a line empty if the answer is not known yet.
+pub fn read_answers(year: i16, day: u8) -> [Option<String>; 2] {
+    crate::year_input_path(year, "answers", day)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|contents| parse_answers(&contents))
+        .unwrap_or_default()
+}
+
+/// Stores the answer to a part in `answers/NN.txt`, keeping the answer to the other part.
+pub fn write_answer(year: i16, day: u8, part: u8, answer: &str) -> io::Result<()> {
+    let mut answers = read_answers(year, day);
+    answers[usize::from(part) - 1] = Some(answer.to_string());
+
+    let path = crate::year_input_path(year, "answers", day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, format_answers(&answers))
+}
+
+fn format_answers(answers: &[Option<String>; 2]) -> String {
+    answers
+        .iter()
+        .map(|answer| answer.as_deref().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub(crate) fn parse_answers(contents: &str) -> [Option<String>; 2] {
+    let mut lines = contents.lines().map(|line| {
+        let line = line.trim();
+        if line.is_empty() {
+            None
+        } else {
+            Some(line.to_string())
+        }
+    });
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No expected answer is recorded for this part.
+    Missing,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "✅ pass"),
+            Verdict::Fail { expected, actual } => write!(
+                f,
+                "❌ fail (expected {}, got {})",
+                expected,
+                actual.as_deref().unwrap_or("not solved")
+            ),
+            Verdict::Missing => write!(f, "❔ missing"),
+        }
+    }
+}
+
+pub fn verify(solution: &Solution, expected: Option<&str>) -> Verdict {
+    match expected {
+        None => Verdict::Missing,
+        Some(expected) if solution.answer.as_deref() == Some(expected) => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.to_string(),
+            actual: solution.answer.clone(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn solution(answer: Option<&str>) -> Solution {
+        Solution::for_test(1, 1, answer, Duration::ZERO)
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("24000\n45000\n"),
+            [Some("24000".to_string()), Some("45000".to_string())]
+        );
+        assert_eq!(parse_answers("\nMCD"), [None, Some("MCD".to_string())]);
+        assert_eq!(parse_answers("CMZ"), [Some("CMZ".to_string()), None]);
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn test_format_answers() {
+        let answers = [None, Some("MCD".to_string())];
+        assert_eq!(format_answers(&answers), "\nMCD");
+        assert_eq!(parse_answers(&format_answers(&answers)), answers);
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(verify(&solution(Some("42")), Some("42")), Verdict::Pass);
+        assert_eq!(verify(&solution(Some("42")), None), Verdict::Missing);
+        assert_eq!(
+            verify(&solution(Some("41")), Some("42")),
+            Verdict::Fail {
+                expected: "42".to_string(),
+                actual: Some("41".to_string())
+            }
+        );
+        assert_eq!(
+            verify(&solution(None), Some("42")).to_string(),
+            "❌ fail (expected 42, got not solved)"
+        );
+    }
+}